@@ -0,0 +1,41 @@
+﻿#nullable enable
+using Hikari.NativeBind;
+using System;
+
+namespace Hikari;
+
+/// <summary>Cursor image, which can be used by any screen. (See <see cref="Mouse.SetCursor(CustomCursor)"/>)</summary>
+public sealed partial class CustomCursor
+{
+    private Rust.OptionBox<CH.CustomCursor> _native;
+
+    internal Rust.Ref<CH.CustomCursor> NativeRef => _native.Unwrap();
+
+    /// <param name="rgba">RGBA pixels, whose length must be <c>width * height * 4</c></param>
+    /// <param name="width">width of the image</param>
+    /// <param name="height">height of the image</param>
+    /// <param name="hotspotX">x position of the hotspot in the image</param>
+    /// <param name="hotspotY">y position of the hotspot in the image</param>
+    [Owned(nameof(Release))]
+    private CustomCursor(ReadOnlySpan<byte> rgba, u16 width, u16 height, u16 hotspotX, u16 hotspotY)
+    {
+        _native = EngineCore.CreateCustomCursor(rgba, width, height, hotspotX, hotspotY);
+    }
+
+    ~CustomCursor() => Release(false);
+
+    private void Release()
+    {
+        Release(true);
+        GC.SuppressFinalize(this);
+    }
+
+    private void Release(bool disposing)
+    {
+        if(InterlockedEx.Exchange(ref _native, Rust.OptionBox<CH.CustomCursor>.None).IsSome(out var native)) {
+            native.DestroyCustomCursor();
+            if(disposing) {
+            }
+        }
+    }
+}
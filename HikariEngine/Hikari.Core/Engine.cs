@@ -3,6 +3,7 @@ using Cysharp.Threading.Tasks;
 using Hikari.NativeBind;
 using System;
 using System.Collections.Generic;
+using System.Collections.Immutable;
 using System.ComponentModel;
 using System.Runtime.InteropServices;
 using System.Text;
@@ -17,7 +18,7 @@ public static class Engine
 
     private static Action<Screen>? _onScreenInit;
 
-    public static void Run(in ScreenConfig screenConfig, Func<Screen, UniTask> onScreenInit)
+    public static void Run(in ScreenConfig screenConfig, Func<Screen, UniTask> onScreenInit, EngineExitPolicy exitPolicy = EngineExitPolicy.LastScreenClosed)
     {
         Run(in screenConfig, screen =>
         {
@@ -33,10 +34,10 @@ public static class Engine
                     }
                 },
                 (onScreenInit, screen));
-        });
+        }, exitPolicy);
     }
 
-    public static void Run(in ScreenConfig screenConfig, Func<Screen, ValueTask> onScreenInit)
+    public static void Run(in ScreenConfig screenConfig, Func<Screen, ValueTask> onScreenInit, EngineExitPolicy exitPolicy = EngineExitPolicy.LastScreenClosed)
     {
         Run(in screenConfig, screen =>
         {
@@ -52,10 +53,10 @@ public static class Engine
                     }
                 },
                 (onScreenInit, screen));
-        });
+        }, exitPolicy);
     }
 
-    public static void Run(in ScreenConfig screenConfig, Func<Screen, Task> onScreenInit)
+    public static void Run(in ScreenConfig screenConfig, Func<Screen, Task> onScreenInit, EngineExitPolicy exitPolicy = EngineExitPolicy.LastScreenClosed)
     {
         Run(in screenConfig, screen =>
         {
@@ -71,10 +72,10 @@ public static class Engine
                     }
                 },
                 (onScreenInit, screen));
-        });
+        }, exitPolicy);
     }
 
-    public static void Run(in ScreenConfig screenConfig, Action<Screen> onScreenInit)
+    public static void Run(in ScreenConfig screenConfig, Action<Screen> onScreenInit, EngineExitPolicy exitPolicy = EngineExitPolicy.LastScreenClosed)
     {
         ArgumentNullException.ThrowIfNull(onScreenInit);
         CheckPlatformBackend(screenConfig.Backend);
@@ -90,19 +91,47 @@ public static class Engine
             OnRedrawRequested = _onRedrawRequested,
             OnCleared = _onCleared,
             OnResized = _onResized,
+            OnScaleFactorChanged = _onScaleFactorChanged,
             OnKeyboardInput = _onKeyboardInput,
+            OnKeyInput = _onKeyInput,
             OnCharReceived = _onCharReceived,
             OnMouseButton = _onMouseButon,
             OnImeInput = _onImeInput,
             OnWheel = _onWheel,
+            OnMouseScroll = _onMouseScroll,
+            OnTouch = _onTouch,
             OnCursorMoved = _onCursorMoved,
+            OnRawMouseMotion = _onRawMouseMotion,
             OnCursorEnteredLeft = _onCursorEnteredLeft,
+            OnFileHovered = _onFileHovered,
+            OnFileDropped = _onFileDropped,
+            OnFileHoverCancelled = _onFileHoverCancelled,
+            OnFocused = _onFocused,
+            OnOccluded = _onOccluded,
+            OnThemeChanged = _onThemeChanged,
+            OnDeviceLost = _onDeviceLost,
             OnClosing = _onClosing,
             OnClosed = _onClosed,
+            ExitPolicy = exitPolicy,
         };
         EngineCore.EngineStart(engineConfig, screenConfig);
     }
 
+    /// <summary>
+    /// Get the adapters (GPUs) of the backend.
+    /// The index of the adapter can be used as <see cref="ScreenConfig.AdapterIndex"/> with the same backend.
+    /// </summary>
+    public static ImmutableArray<GpuAdapterInfo> EnumerateAdapters(GraphicsBackend backend)
+    {
+        return EngineCore.EnumerateAdapters(backend.MapOrThrow());
+    }
+
+    /// <summary>Exit the engine. All remaining screens are closed.</summary>
+    public static void Exit()
+    {
+        EngineCore.EngineExit();
+    }
+
     private static void CheckPlatformBackend(GraphicsBackend backend)
     {
         if(RuntimeInformation.IsOSPlatform(OSPlatform.Windows)) {
@@ -155,11 +184,25 @@ public static class Engine
             _screens[id].OnResized(width, height);
         };
 
+    private static readonly EngineCoreScaleFactorChangedAction _onScaleFactorChanged =
+        (CH.ScreenId id, f64 scaleFactor, ref CH.SizeU32 innerSize) =>
+        {
+            var size = new Vector2u(innerSize.width, innerSize.height);
+            _screens[id].OnScaleFactorChanged((float)scaleFactor, ref size);
+            innerSize.width = size.X;
+            innerSize.height = size.Y;
+        };
+
     private static readonly Action<CH.ScreenId, CH.KeyCode, bool> _onKeyboardInput =
         (CH.ScreenId id, CH.KeyCode key, bool pressed) =>
         {
             _screens[id].Keyboard.OnKeyboardInput(key, pressed);
         };
+    private static readonly EngineCoreKeyInputAction _onKeyInput =
+        (CH.ScreenId id, in CH.KeyInputData input) =>
+        {
+            _screens[id].Keyboard.OnKeyInput(input);
+        };
     private static readonly Action<CH.ScreenId, Rune> _onCharReceived =
         (CH.ScreenId id, Rune input) =>
         {
@@ -184,18 +227,81 @@ public static class Engine
             _screens[id].Mouse.OnWheel(new Vector2(xDelta, yDelta));
         };
 
+    private static readonly EngineCoreMouseScrollAction _onMouseScroll =
+        (CH.ScreenId id, in CH.MouseScrollData scroll) =>
+        {
+            _screens[id].Mouse.OnScroll(scroll);
+        };
+
+    private static readonly EngineCoreTouchAction _onTouch =
+        (CH.ScreenId id, in CH.TouchData touch) =>
+        {
+            _screens[id].OnTouch(touch);
+        };
+
     private static readonly Action<CH.ScreenId, f32, f32> _onCursorMoved =
         (CH.ScreenId id, f32 x, f32 y) =>
         {
             _screens[id].Mouse.OnCursorMoved(new Vector2(x, y));
         };
 
+    private static readonly Action<CH.ScreenId, f64, f64> _onRawMouseMotion =
+        (CH.ScreenId id, f64 xDelta, f64 yDelta) =>
+        {
+            _screens[id].Mouse.OnRawMotion(new Vector2((f32)xDelta, (f32)yDelta));
+        };
+
     private static readonly Action<CH.ScreenId, bool> _onCursorEnteredLeft =
         (CH.ScreenId id, bool entered) =>
         {
             _screens[id].Mouse.OnCursorEnteredLeft(entered);
         };
 
+    private static readonly Action<CH.ScreenId, string, f32, f32> _onFileHovered =
+        (CH.ScreenId id, string path, f32 x, f32 y) =>
+        {
+            _screens[id].OnFileHovered(path, new Vector2(x, y));
+        };
+
+    private static readonly Action<CH.ScreenId, string, f32, f32> _onFileDropped =
+        (CH.ScreenId id, string path, f32 x, f32 y) =>
+        {
+            _screens[id].OnFileDropped(path, new Vector2(x, y));
+        };
+
+    private static readonly Action<CH.ScreenId> _onFileHoverCancelled =
+        (CH.ScreenId id) =>
+        {
+            _screens[id].OnFileHoverCancelled();
+        };
+
+    private static readonly Action<CH.ScreenId, bool> _onFocused =
+        (CH.ScreenId id, bool focused) =>
+        {
+            _screens[id].OnFocused(focused);
+        };
+
+    private static readonly Action<CH.ScreenId, bool> _onOccluded =
+        (CH.ScreenId id, bool occluded) =>
+        {
+            _screens[id].OnOccluded(occluded);
+        };
+
+    private static readonly Action<CH.ScreenId, CH.Theme> _onThemeChanged =
+        (CH.ScreenId id, CH.Theme theme) =>
+        {
+            _screens[id].OnThemeChanged(theme.MapOrThrow());
+        };
+
+    private static readonly Action<CH.ScreenId, Wgpu.DeviceLostReason, string> _onDeviceLost =
+        (CH.ScreenId id, Wgpu.DeviceLostReason reason, string message) =>
+        {
+            // The screen may be already closed.
+            if(_screens.TryGetValue(id, out var screen)) {
+                screen.OnDeviceLost(reason.MapOrThrow(), message);
+            }
+        };
+
     private static readonly EngineCoreScreenClosingAction _onClosing =
         (CH.ScreenId id, ref bool cancel) =>
         {
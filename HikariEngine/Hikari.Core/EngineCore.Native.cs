@@ -21,6 +21,12 @@ static unsafe partial class EngineCore
     [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
     private static partial ApiResult hikari_create_screen(CH.ScreenConfig* config);
 
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_engine_exit();
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_close(Rust.Ref<CH.Screen> screen);
+
     [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
     private static partial ApiResult hikari_screen_resize_surface(
         Rust.Ref<CH.Screen> screen,
@@ -56,11 +62,63 @@ static unsafe partial class EngineCore
     private static partial void hikari_present_surface_texture(
         Rust.Box<Wgpu.SurfaceTexture> surface_texture);
 
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_post_to_main_thread(
+        delegate* unmanaged[Cdecl]<usize, void> callback,
+        usize token,
+        delegate* unmanaged[Cdecl]<usize, ApiResult, void> completed);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiValueResult<usize> hikari_enumerate_adapters(
+        Wgpu.Backends backends,
+        usize token,
+        delegate* unmanaged[Cdecl]<usize, usize, CH.AdapterInfo*, void> callback);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_recreate_device(
+        Rust.Ref<CH.Screen> screen,
+        CH.ScreenInfo* info);
+
     [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
     private static partial ApiResult hikari_screen_set_title(
         Rust.Ref<CH.Screen> screen,
         CH.Slice<u8> title);
 
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_icon(
+        Rust.Ref<CH.Screen> screen,
+        CH.Slice<u8> rgba,
+        u32 width,
+        u32 height);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiValueResult<CH.Opt<CH.Theme>> hikari_screen_get_theme(Rust.Ref<CH.Screen> screen);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_get_surface_capabilities(
+        Rust.Ref<CH.Screen> screen,
+        usize token,
+        delegate* unmanaged[Cdecl]<usize, CH.SurfaceCapabilities*, void> callback);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_present_mode(
+        Rust.Ref<CH.Screen> screen,
+        CH.PresentMode present_mode);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiValueResult<CH.Features> hikari_screen_get_features(Rust.Ref<CH.Screen> screen);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiValueResult<Wgpu.Limits> hikari_screen_get_limits(Rust.Ref<CH.Screen> screen);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_theme(Rust.Ref<CH.Screen> screen, CH.Theme theme);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_visible(
+        Rust.Ref<CH.Screen> screen,
+        [MarshalAs(UnmanagedType.U1)] bool visible);
+
     [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
     private static partial ApiBoxResult<Wgpu.RenderPass> hikari_create_render_pass(
         Rust.MutRef<Wgpu.CommandEncoder> command_encoder,
@@ -118,6 +176,125 @@ static unsafe partial class EngineCore
         CH.MonitorId* buf_out,
         usize buflen);
 
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiValueResult<CH.Opt<CH.MonitorId>> hikari_primary_monitor(
+        Rust.Ref<CH.Screen> screen);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiValueResult<CH.MonitorInfo> hikari_monitor_get_info(
+        CH.MonitorId monitor_id,
+        u8* name_buf,
+        usize name_buflen);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiValueResult<usize> hikari_monitor_video_mode_count(
+        CH.MonitorId monitor_id);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiValueResult<usize> hikari_monitor_video_modes(
+        CH.MonitorId monitor_id,
+        CH.VideoMode* buf,
+        usize buflen);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_style(
+        Rust.Ref<CH.Screen> screen,
+        CH.WindowStyle style);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_exclusive_fullscreen(
+        Rust.Ref<CH.Screen> screen,
+        CH.MonitorId monitor_id,
+        CH.VideoMode* video_mode);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_min_inner_size(
+        Rust.Ref<CH.Screen> screen,
+        CH.Opt<CH.SizeU32> size);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_max_inner_size(
+        Rust.Ref<CH.Screen> screen,
+        CH.Opt<CH.SizeU32> size);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_maximized(
+        Rust.Ref<CH.Screen> screen,
+        [MarshalAs(UnmanagedType.U1)] bool maximized);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiValueResult<bool_u8> hikari_screen_is_maximized(
+        Rust.Ref<CH.Screen> screen);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_minimized(
+        Rust.Ref<CH.Screen> screen,
+        [MarshalAs(UnmanagedType.U1)] bool minimized);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiValueResult<CH.Opt<bool_u8>> hikari_screen_is_minimized(
+        Rust.Ref<CH.Screen> screen);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_restore(
+        Rust.Ref<CH.Screen> screen);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_always_on_top(
+        Rust.Ref<CH.Screen> screen,
+        [MarshalAs(UnmanagedType.U1)] bool always_on_top);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiValueResult<bool_u8> hikari_screen_is_always_on_top(
+        Rust.Ref<CH.Screen> screen);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_decorations(
+        Rust.Ref<CH.Screen> screen,
+        [MarshalAs(UnmanagedType.U1)] bool decorations);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiValueResult<bool_u8> hikari_screen_is_decorated(
+        Rust.Ref<CH.Screen> screen);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_cursor_grab(
+        Rust.Ref<CH.Screen> screen,
+        CH.CursorGrabMode mode);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_cursor_visible(
+        Rust.Ref<CH.Screen> screen,
+        [MarshalAs(UnmanagedType.U1)] bool visible);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_cursor_position(
+        Rust.Ref<CH.Screen> screen,
+        f32 x,
+        f32 y);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_cursor_icon(
+        Rust.Ref<CH.Screen> screen,
+        CH.CursorIcon icon);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiBoxResult<CH.CustomCursor> hikari_create_custom_cursor(
+        CH.Slice<u8> rgba,
+        u16 width,
+        u16 height,
+        u16 hotspot_x,
+        u16 hotspot_y);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial void hikari_destroy_custom_cursor(
+        Rust.Box<CH.CustomCursor> cursor);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_screen_set_custom_cursor(
+        Rust.Ref<CH.Screen> screen,
+        Rust.Ref<CH.CustomCursor> cursor);
+
     [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
     private static partial ApiResult hikari_write_texture(
         Rust.Ref<CH.Screen> screen,
@@ -363,6 +540,19 @@ static unsafe partial class EngineCore
         u32 x,
         u32 y);
 
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_set_ime_cursor_area(
+        Rust.Ref<CH.Screen> screen,
+        i32 x,
+        i32 y,
+        u32 width,
+        u32 height);
+
+    [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
+    private static partial ApiResult hikari_set_ime_purpose(
+        Rust.Ref<CH.Screen> screen,
+        CH.ImePurpose purpose);
+
     [LibraryImport(CoreDll), UnmanagedCallConv(CallConvs = [typeof(CallConvCdecl)])]
     private static partial usize hikari_get_tls_last_error_len();
 
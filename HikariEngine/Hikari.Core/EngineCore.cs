@@ -1,5 +1,6 @@
 ﻿#nullable enable
 using System;
+using System.Collections.Immutable;
 using System.Runtime.CompilerServices;
 using System.Runtime.InteropServices;
 using System.Diagnostics;
@@ -46,11 +47,29 @@ internal unsafe static partial class EngineCore
             event_closing = new(&EventClosing),
             event_closed = new(&EventClosed),
             debug_println = new(&DebugPrintln),
+            event_mouse_scroll = new(&EventMouseScroll),
+            event_scale_factor_changed = new(&EventScaleFactorChanged),
+            event_touch = new(&EventTouch),
+            event_key_input = new(&EventKeyInput),
+            event_raw_mouse_motion = new(&EventRawMouseMotion),
+            event_file_hovered = new(&EventFileHovered),
+            event_file_dropped = new(&EventFileDropped),
+            event_file_hover_cancelled = new(&EventFileHoverCancelled),
+            event_focused = new(&EventFocused),
+            event_occluded = new(&EventOccluded),
+            exit_policy = config.ExitPolicy.MapOrThrow(),
+            event_theme_changed = new(&EventThemeChanged),
+            event_device_lost = new(&EventDeviceLost),
         };
 
-        var screenConfigNative = screenConfig.ToCoreType();
-
-        hikari_engine_start(&engineConfigNative, &screenConfigNative).Validate();
+        var pins = new PinHandleHolder();
+        try {
+            var screenConfigNative = screenConfig.ToCoreType(pins);
+            hikari_engine_start(&engineConfigNative, &screenConfigNative).Validate();
+        }
+        finally {
+            pins.Dispose();
+        }
         return;
 
 
@@ -100,12 +119,24 @@ internal unsafe static partial class EngineCore
             _config.OnResized(id, width, height);
         }
 
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void EventScaleFactorChanged(CH.ScreenId id, f64 scale_factor, CH.SizeU32* inner_size)
+        {
+            _config.OnScaleFactorChanged(id, scale_factor, ref *inner_size);
+        }
+
         [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
         static void EventKeyboard(CH.ScreenId id, CH.KeyCode key, bool pressed)
         {
             _config.OnKeyboardInput(id, key, pressed);
         }
 
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void EventKeyInput(CH.ScreenId id, CH.KeyInputData* input)
+        {
+            _config.OnKeyInput(id, in *input);
+        }
+
         [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
         static void EventCharReceived(CH.ScreenId id, Rune input)
         {
@@ -130,6 +161,24 @@ internal unsafe static partial class EngineCore
             _config.OnWheel(id, x_delta, y_delta);
         }
 
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void EventMouseScroll(CH.ScreenId id, CH.MouseScrollData* scroll)
+        {
+            _config.OnMouseScroll(id, in *scroll);
+        }
+
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void EventTouch(CH.ScreenId id, CH.TouchData* touch)
+        {
+            _config.OnTouch(id, in *touch);
+        }
+
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void EventRawMouseMotion(CH.ScreenId id, f64 x_delta, f64 y_delta)
+        {
+            _config.OnRawMouseMotion(id, x_delta, y_delta);
+        }
+
         [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
         static void EventCursorMoved(CH.ScreenId id, f32 x, f32 y)
         {
@@ -142,6 +191,65 @@ internal unsafe static partial class EngineCore
             _config.OnCursorEnteredLeft(id, entered);
         }
 
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void EventFileHovered(CH.ScreenId id, CH.SliceU8 path, f32 x, f32 y)
+        {
+            _config.OnFileHovered(id, Encoding.UTF8.GetString(path.AsSpan()), x, y);
+        }
+
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void EventFileDropped(CH.ScreenId id, CH.SliceU8 path, f32 x, f32 y)
+        {
+            _config.OnFileDropped(id, Encoding.UTF8.GetString(path.AsSpan()), x, y);
+        }
+
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void EventFileHoverCancelled(CH.ScreenId id)
+        {
+            _config.OnFileHoverCancelled(id);
+        }
+
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void EventFocused(CH.ScreenId id, bool focused)
+        {
+            _config.OnFocused(id, focused);
+        }
+
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void EventOccluded(CH.ScreenId id, bool occluded)
+        {
+            _config.OnOccluded(id, occluded);
+        }
+
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void EventThemeChanged(CH.ScreenId id, CH.Theme theme)
+        {
+            _config.OnThemeChanged(id, theme);
+        }
+
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void EventDeviceLost(CH.ScreenId id, Wgpu.DeviceLostReason reason, CH.SliceU8 message)
+        {
+            // This is invoked from any thread, so the event is raised on the main thread.
+            var handle = GCHandle.Alloc(new DeviceLostData(id, reason, Encoding.UTF8.GetString(message.AsSpan())));
+            try {
+                hikari_post_to_main_thread(&RaiseDeviceLost, (usize)GCHandle.ToIntPtr(handle), null).Validate();
+            }
+            catch {
+                // The engine is exiting. The event is no longer needed.
+                handle.Free();
+            }
+        }
+
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void RaiseDeviceLost(usize token)
+        {
+            var handle = GCHandle.FromIntPtr((nint)token);
+            var data = SafeCast.NotNullAs<DeviceLostData>(handle.Target);
+            handle.Free();
+            _config.OnDeviceLost(data.Id, data.Reason, data.Message);
+        }
+
         [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
         static void EventClosing(CH.ScreenId id, bool* mut_cancel)
         {
@@ -164,10 +272,33 @@ internal unsafe static partial class EngineCore
         }
     }
 
+    public static void EngineExit()
+    {
+        hikari_engine_exit().Validate();
+    }
+
+    public static void ScreenClose(this Rust.Ref<CH.Screen> screen)
+    {
+        hikari_screen_close(screen).Validate();
+    }
+
+    public static CH.ScreenInfo ScreenRecreateDevice(this Rust.Ref<CH.Screen> screen)
+    {
+        CH.ScreenInfo info;
+        hikari_screen_recreate_device(screen, &info).Validate();
+        return info;
+    }
+
     public static void CreateScreen(in ScreenConfig config)
     {
-        var screenConfig = config.ToCoreType();
-        hikari_create_screen(&screenConfig).Validate();
+        var pins = new PinHandleHolder();
+        try {
+            var screenConfig = config.ToCoreType(pins);
+            hikari_create_screen(&screenConfig).Validate();
+        }
+        finally {
+            pins.Dispose();
+        }
     }
 
     [MethodImpl(MethodImplOptions.AggressiveInlining)]
@@ -230,6 +361,90 @@ internal unsafe static partial class EngineCore
         }
     }
 
+    public unsafe static void ScreenSetIcon(this Rust.Ref<CH.Screen> screen, ReadOnlySpan<byte> rgba, u32 width, u32 height)
+    {
+        fixed(byte* p = rgba) {
+            var rgbaRaw = new CH.Slice<byte>(p, rgba.Length);
+            hikari_screen_set_icon(screen, rgbaRaw, width, height).Validate();
+        }
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static CH.Opt<CH.Theme> ScreenGetTheme(this Rust.Ref<CH.Screen> screen)
+    {
+        return hikari_screen_get_theme(screen).Validate();
+    }
+
+    public static SurfaceCapabilities ScreenGetSurfaceCapabilities(this Rust.Ref<CH.Screen> screen)
+    {
+        var result = new StrongBox<SurfaceCapabilities>();
+        var handle = GCHandle.Alloc(result);
+        try {
+            hikari_screen_get_surface_capabilities(screen, (usize)GCHandle.ToIntPtr(handle), &OnCapabilities).Validate();
+        }
+        finally {
+            handle.Free();
+        }
+        return result.Value;
+
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void OnCapabilities(usize token, CH.SurfaceCapabilities* caps)
+        {
+            var result = SafeCast.NotNullAs<StrongBox<SurfaceCapabilities>>(GCHandle.FromIntPtr((nint)token).Target);
+            var formats = ImmutableArray.CreateBuilder<TextureFormat>();
+            foreach(var format in new ReadOnlySpan<CH.TextureFormat>(caps->formats.data, checked((int)caps->formats.len))) {
+                formats.Add(format.MapOrThrow());
+            }
+            var presentModes = ImmutableArray.CreateBuilder<SurfacePresentMode>();
+            foreach(var mode in new ReadOnlySpan<CH.PresentMode>(caps->present_modes.data, checked((int)caps->present_modes.len))) {
+                // Auto modes are not reported by the surface.
+                if(mode.TryMapTo(out SurfacePresentMode presentMode)) {
+                    presentModes.Add(presentMode);
+                }
+            }
+            var alphaModes = ImmutableArray.CreateBuilder<SurfaceAlphaMode>();
+            foreach(var mode in new ReadOnlySpan<CH.CompositeAlphaMode>(caps->alpha_modes.data, checked((int)caps->alpha_modes.len))) {
+                alphaModes.Add(mode.MapOrThrow());
+            }
+            result.Value = new SurfaceCapabilities
+            {
+                Formats = formats.ToImmutable(),
+                PresentModes = presentModes.ToImmutable(),
+                AlphaModes = alphaModes.ToImmutable(),
+            };
+        }
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetPresentMode(this Rust.Ref<CH.Screen> screen, CH.PresentMode presentMode)
+    {
+        hikari_screen_set_present_mode(screen, presentMode).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static CH.Features ScreenGetFeatures(this Rust.Ref<CH.Screen> screen)
+    {
+        return hikari_screen_get_features(screen).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static Wgpu.Limits ScreenGetLimits(this Rust.Ref<CH.Screen> screen)
+    {
+        return hikari_screen_get_limits(screen).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetTheme(this Rust.Ref<CH.Screen> screen, CH.Theme theme)
+    {
+        hikari_screen_set_theme(screen, theme).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetVisible(this Rust.Ref<CH.Screen> screen, bool visible)
+    {
+        hikari_screen_set_visible(screen, visible).Validate();
+    }
+
     [MethodImpl(MethodImplOptions.AggressiveInlining)]
     public static Rust.Box<Wgpu.RenderPass> CreateRenderPass(this Rust.MutRef<Wgpu.CommandEncoder> commandEncoder, in CH.RenderPassDescriptor desc)
     {
@@ -308,6 +523,260 @@ internal unsafe static partial class EngineCore
         }
     }
 
+    public static MonitorId? PrimaryMonitor(this Rust.Ref<CH.Screen> screen)
+    {
+        return hikari_primary_monitor(screen)
+            .Validate()
+            .TryGetValue(out var monitor) ? new MonitorId(monitor) : null;
+    }
+
+    public static MonitorInfo GetInfo(this CH.MonitorId monitorId)
+    {
+        const int BufLen = 256;
+        var buf = stackalloc u8[BufLen];
+        var info = hikari_monitor_get_info(monitorId, buf, BufLen).Validate();
+        string? name;
+        if(info.name_len == 0) {
+            name = null;
+        }
+        else if(info.name_len <= BufLen) {
+            name = Encoding.UTF8.GetString(buf, (int)info.name_len);
+        }
+        else {
+            // Call again with the buffer of the whole name.
+            var array = new u8[(int)info.name_len];
+            fixed(u8* p = array) {
+                info = hikari_monitor_get_info(monitorId, p, (usize)array.Length).Validate();
+            }
+            name = Encoding.UTF8.GetString(array, 0, int.Min((int)info.name_len, array.Length));
+        }
+        return new MonitorInfo
+        {
+            Name = name,
+            Position = new Vector2i(info.x, info.y),
+            Size = new Vector2u(info.width, info.height),
+            ScaleFactor = info.scale_factor,
+            RefreshRateMillihertz = info.refresh_rate_millihertz.TryGetValue(out var rate) ? rate : null,
+        };
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static usize VideoModeCount(
+        this CH.MonitorId monitorId)
+    {
+        return hikari_monitor_video_mode_count(monitorId).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static usize VideoModes(
+        this CH.MonitorId monitorId,
+        Span<CH.VideoMode> buf)
+    {
+        fixed(CH.VideoMode* p = buf) {
+            return hikari_monitor_video_modes(monitorId, p, (usize)buf.Length).Validate();
+        }
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetStyle(
+        this Rust.Ref<CH.Screen> screen,
+        CH.WindowStyle style)
+    {
+        hikari_screen_set_style(screen, style).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetExclusiveFullscreen(
+        this Rust.Ref<CH.Screen> screen,
+        CH.MonitorId monitorId,
+        in CH.VideoMode videoMode)
+    {
+        fixed(CH.VideoMode* p = &videoMode) {
+            hikari_screen_set_exclusive_fullscreen(screen, monitorId, p).Validate();
+        }
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetMinInnerSize(
+        this Rust.Ref<CH.Screen> screen,
+        Vector2u? size)
+    {
+        CH.SizeU32? sizeNative = size is Vector2u s ? new CH.SizeU32 { width = s.X, height = s.Y } : null;
+        hikari_screen_set_min_inner_size(screen, CH.Opt.From(sizeNative)).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetMaxInnerSize(
+        this Rust.Ref<CH.Screen> screen,
+        Vector2u? size)
+    {
+        CH.SizeU32? sizeNative = size is Vector2u s ? new CH.SizeU32 { width = s.X, height = s.Y } : null;
+        hikari_screen_set_max_inner_size(screen, CH.Opt.From(sizeNative)).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetMaximized(
+        this Rust.Ref<CH.Screen> screen,
+        bool maximized)
+    {
+        hikari_screen_set_maximized(screen, maximized).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static bool ScreenIsMaximized(
+        this Rust.Ref<CH.Screen> screen)
+    {
+        byte isMaximized = hikari_screen_is_maximized(screen).Validate();
+        return isMaximized != 0;
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetMinimized(
+        this Rust.Ref<CH.Screen> screen,
+        bool minimized)
+    {
+        hikari_screen_set_minimized(screen, minimized).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static bool? ScreenIsMinimized(
+        this Rust.Ref<CH.Screen> screen)
+    {
+        return hikari_screen_is_minimized(screen).Validate().TryGetValue(out var isMinimized) ? isMinimized != 0 : null;
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenRestore(
+        this Rust.Ref<CH.Screen> screen)
+    {
+        hikari_screen_restore(screen).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetAlwaysOnTop(
+        this Rust.Ref<CH.Screen> screen,
+        bool alwaysOnTop)
+    {
+        hikari_screen_set_always_on_top(screen, alwaysOnTop).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static bool ScreenIsAlwaysOnTop(
+        this Rust.Ref<CH.Screen> screen)
+    {
+        byte isAlwaysOnTop = hikari_screen_is_always_on_top(screen).Validate();
+        return isAlwaysOnTop != 0;
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetDecorations(
+        this Rust.Ref<CH.Screen> screen,
+        bool decorations)
+    {
+        hikari_screen_set_decorations(screen, decorations).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static bool ScreenIsDecorated(
+        this Rust.Ref<CH.Screen> screen)
+    {
+        byte isDecorated = hikari_screen_is_decorated(screen).Validate();
+        return isDecorated != 0;
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetCursorGrab(
+        this Rust.Ref<CH.Screen> screen,
+        CH.CursorGrabMode mode)
+    {
+        hikari_screen_set_cursor_grab(screen, mode).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetCursorVisible(
+        this Rust.Ref<CH.Screen> screen,
+        bool visible)
+    {
+        hikari_screen_set_cursor_visible(screen, visible).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetCursorPosition(
+        this Rust.Ref<CH.Screen> screen,
+        f32 x,
+        f32 y)
+    {
+        hikari_screen_set_cursor_position(screen, x, y).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetCursorIcon(
+        this Rust.Ref<CH.Screen> screen,
+        CH.CursorIcon icon)
+    {
+        hikari_screen_set_cursor_icon(screen, icon).Validate();
+    }
+
+    public static Rust.Box<CH.CustomCursor> CreateCustomCursor(
+        ReadOnlySpan<byte> rgba,
+        u16 width,
+        u16 height,
+        u16 hotspotX,
+        u16 hotspotY)
+    {
+        fixed(byte* p = rgba) {
+            var rgbaRaw = new CH.Slice<u8>(p, rgba.Length);
+            return hikari_create_custom_cursor(rgbaRaw, width, height, hotspotX, hotspotY).Validate();
+        }
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void DestroyCustomCursor(
+        this Rust.Box<CH.CustomCursor> handle)
+    {
+        handle.ThrowIfInvalid();
+        hikari_destroy_custom_cursor(handle);
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void ScreenSetCustomCursor(
+        this Rust.Ref<CH.Screen> screen,
+        Rust.Ref<CH.CustomCursor> cursor)
+    {
+        hikari_screen_set_custom_cursor(screen, cursor).Validate();
+    }
+
+    public static ImmutableArray<GpuAdapterInfo> EnumerateAdapters(Wgpu.Backends backends)
+    {
+        var builder = ImmutableArray.CreateBuilder<GpuAdapterInfo>();
+        var handle = GCHandle.Alloc(builder);
+        try {
+            hikari_enumerate_adapters(backends, (usize)GCHandle.ToIntPtr(handle), &OnAdapter).Validate();
+        }
+        finally {
+            handle.Free();
+        }
+        return builder.ToImmutable();
+
+        [UnmanagedCallersOnly(CallConvs = [typeof(CallConvCdecl)])]
+        static void OnAdapter(usize token, usize index, CH.AdapterInfo* info)
+        {
+            var builder = SafeCast.NotNullAs<ImmutableArray<GpuAdapterInfo>.Builder>(GCHandle.FromIntPtr((nint)token).Target);
+            var utf8 = Encoding.UTF8;
+            builder.Add(new GpuAdapterInfo
+            {
+                Index = checked((int)index),
+                Name = utf8.GetString(info->name.AsSpan()),
+                Vendor = info->vendor,
+                Device = info->device,
+                DeviceType = info->device_type.MapOrThrow(),
+                Driver = utf8.GetString(info->driver.AsSpan()),
+                DriverInfo = utf8.GetString(info->driver_info.AsSpan()),
+                Backend = info->backend.MapOrThrow(),
+            });
+        }
+    }
+
     [MethodImpl(MethodImplOptions.AggressiveInlining)]
     public static MonitorId? CurrentMonitor(this Rust.Ref<CH.Screen> screen)
     {
@@ -734,6 +1203,25 @@ internal unsafe static partial class EngineCore
         hikari_set_ime_position(screen, x, y).Validate();
     }
 
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void SetImeCursorArea(
+        this Rust.Ref<CH.Screen> screen,
+        i32 x,
+        i32 y,
+        u32 width,
+        u32 height)
+    {
+        hikari_set_ime_cursor_area(screen, x, y, width, height).Validate();
+    }
+
+    [MethodImpl(MethodImplOptions.AggressiveInlining)]
+    public static void SetImePurpose(
+        this Rust.Ref<CH.Screen> screen,
+        CH.ImePurpose purpose)
+    {
+        hikari_set_ime_purpose(screen, purpose).Validate();
+    }
+
     [MethodImpl(MethodImplOptions.NoInlining)]
     private static EngineCoreException GetTlsLastError()
     {
@@ -810,22 +1298,48 @@ internal readonly struct EngineCoreConfig
     public required Action<CH.ScreenId> OnCleared { get; init; }
 
     public required Action<CH.ScreenId, u32, u32> OnResized { get; init; }
+    public required EngineCoreScaleFactorChangedAction OnScaleFactorChanged { get; init; }
 
     public required Action<CH.ScreenId, CH.KeyCode, bool> OnKeyboardInput { get; init; }
+    public required EngineCoreKeyInputAction OnKeyInput { get; init; }
     public required Action<CH.ScreenId, Rune> OnCharReceived { get; init; }
     public required Action<CH.ScreenId, CH.MouseButton, bool> OnMouseButton { get; init; }
     public required EngineCoreImeInputAction OnImeInput { get; init; }
 
     public required Action<CH.ScreenId, f32, f32> OnWheel { get; init; }
+    public required EngineCoreMouseScrollAction OnMouseScroll { get; init; }
+    public required EngineCoreTouchAction OnTouch { get; init; }
     public required Action<CH.ScreenId, f32, f32> OnCursorMoved { get; init; }
+    public required Action<CH.ScreenId, f64, f64> OnRawMouseMotion { get; init; }
     public required Action<CH.ScreenId, bool> OnCursorEnteredLeft { get; init; }
 
+    public required Action<CH.ScreenId, string, f32, f32> OnFileHovered { get; init; }
+    public required Action<CH.ScreenId, string, f32, f32> OnFileDropped { get; init; }
+    public required Action<CH.ScreenId> OnFileHoverCancelled { get; init; }
+
+    public required Action<CH.ScreenId, bool> OnFocused { get; init; }
+    public required Action<CH.ScreenId, bool> OnOccluded { get; init; }
+    public required Action<CH.ScreenId, CH.Theme> OnThemeChanged { get; init; }
+    public required Action<CH.ScreenId, Wgpu.DeviceLostReason, string> OnDeviceLost { get; init; }
+
     public required EngineCoreScreenClosingAction OnClosing { get; init; }
     public required Func<CH.ScreenId, Rust.OptionBox<CH.Screen>> OnClosed { get; init; }
+
+    public required EngineExitPolicy ExitPolicy { get; init; }
 }
 
+internal sealed record DeviceLostData(CH.ScreenId Id, Wgpu.DeviceLostReason Reason, string Message);
+
+internal delegate void EngineCoreKeyInputAction(CH.ScreenId id, in CH.KeyInputData input);
+
 internal delegate void EngineCoreImeInputAction(CH.ScreenId id, in CH.ImeInputData input);
 
+internal delegate void EngineCoreScaleFactorChangedAction(CH.ScreenId id, f64 scaleFactor, ref CH.SizeU32 innerSize);
+
+internal delegate void EngineCoreMouseScrollAction(CH.ScreenId id, in CH.MouseScrollData scroll);
+
+internal delegate void EngineCoreTouchAction(CH.ScreenId id, in CH.TouchData touch);
+
 internal delegate void EngineCoreScreenClosingAction(CH.ScreenId id, ref bool cancel);
 
 
@@ -0,0 +1,19 @@
+﻿#nullable enable
+
+namespace Hikari;
+
+/// <summary>Information of the adapter (GPU), which is the same as <c>wgpu::AdapterInfo</c>.</summary>
+public readonly record struct GpuAdapterInfo
+{
+    /// <summary>index in the adapters of the backend (See <see cref="Engine.EnumerateAdapters"/>)</summary>
+    public required int Index { get; init; }
+    public required string Name { get; init; }
+    /// <summary>PCI id of the vendor (0 if unknown)</summary>
+    public required u32 Vendor { get; init; }
+    /// <summary>PCI id of the adapter (0 if unknown)</summary>
+    public required u32 Device { get; init; }
+    public required GpuDeviceType DeviceType { get; init; }
+    public required string Driver { get; init; }
+    public required string DriverInfo { get; init; }
+    public required GraphicsBackend Backend { get; init; }
+}
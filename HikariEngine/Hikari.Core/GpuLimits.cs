@@ -0,0 +1,135 @@
+﻿#nullable enable
+using Hikari.NativeBind;
+
+namespace Hikari;
+
+/// <summary>Limits of the GPU, which is the same as <c>wgpu::Limits</c>. (The default values are <c>wgpu::Limits::default()</c>.)</summary>
+public readonly record struct GpuLimits
+{
+    public u32 MaxTextureDimension1D { get; init; } = 8192;
+    public u32 MaxTextureDimension2D { get; init; } = 8192;
+    public u32 MaxTextureDimension3D { get; init; } = 2048;
+    public u32 MaxTextureArrayLayers { get; init; } = 256;
+    public u32 MaxBindGroups { get; init; } = 4;
+    public u32 MaxBindingsPerBindGroup { get; init; } = 1000;
+    public u32 MaxDynamicUniformBuffersPerPipelineLayout { get; init; } = 8;
+    public u32 MaxDynamicStorageBuffersPerPipelineLayout { get; init; } = 4;
+    public u32 MaxSampledTexturesPerShaderStage { get; init; } = 16;
+    public u32 MaxSamplersPerShaderStage { get; init; } = 16;
+    public u32 MaxStorageBuffersPerShaderStage { get; init; } = 8;
+    public u32 MaxStorageTexturesPerShaderStage { get; init; } = 4;
+    public u32 MaxUniformBuffersPerShaderStage { get; init; } = 12;
+    public u32 MaxBindingArrayElementsPerShaderStage { get; init; } = 0;
+    public u32 MaxBindingArraySamplerElementsPerShaderStage { get; init; } = 0;
+    public u32 MaxUniformBufferBindingSize { get; init; } = 64 << 10;
+    public u32 MaxStorageBufferBindingSize { get; init; } = 128 << 20;
+    public u32 MaxVertexBuffers { get; init; } = 8;
+    public u64 MaxBufferSize { get; init; } = 256 << 20;
+    public u32 MaxVertexAttributes { get; init; } = 16;
+    public u32 MaxVertexBufferArrayStride { get; init; } = 2048;
+    public u32 MinUniformBufferOffsetAlignment { get; init; } = 256;
+    public u32 MinStorageBufferOffsetAlignment { get; init; } = 256;
+    public u32 MaxInterStageShaderComponents { get; init; } = 60;
+    public u32 MaxColorAttachments { get; init; } = 8;
+    public u32 MaxColorAttachmentBytesPerSample { get; init; } = 32;
+    public u32 MaxComputeWorkgroupStorageSize { get; init; } = 16384;
+    public u32 MaxComputeInvocationsPerWorkgroup { get; init; } = 256;
+    public u32 MaxComputeWorkgroupSizeX { get; init; } = 256;
+    public u32 MaxComputeWorkgroupSizeY { get; init; } = 256;
+    public u32 MaxComputeWorkgroupSizeZ { get; init; } = 64;
+    public u32 MaxComputeWorkgroupsPerDimension { get; init; } = 65535;
+    public u32 MinSubgroupSize { get; init; } = 0;
+    public u32 MaxSubgroupSize { get; init; } = 0;
+    public u32 MaxPushConstantSize { get; init; } = 0;
+    public u32 MaxNonSamplerBindings { get; init; } = 1000000;
+
+    public GpuLimits()
+    {
+    }
+
+    internal Wgpu.Limits ToNative()
+    {
+        return new Wgpu.Limits
+        {
+            max_texture_dimension_1d = MaxTextureDimension1D,
+            max_texture_dimension_2d = MaxTextureDimension2D,
+            max_texture_dimension_3d = MaxTextureDimension3D,
+            max_texture_array_layers = MaxTextureArrayLayers,
+            max_bind_groups = MaxBindGroups,
+            max_bindings_per_bind_group = MaxBindingsPerBindGroup,
+            max_dynamic_uniform_buffers_per_pipeline_layout = MaxDynamicUniformBuffersPerPipelineLayout,
+            max_dynamic_storage_buffers_per_pipeline_layout = MaxDynamicStorageBuffersPerPipelineLayout,
+            max_sampled_textures_per_shader_stage = MaxSampledTexturesPerShaderStage,
+            max_samplers_per_shader_stage = MaxSamplersPerShaderStage,
+            max_storage_buffers_per_shader_stage = MaxStorageBuffersPerShaderStage,
+            max_storage_textures_per_shader_stage = MaxStorageTexturesPerShaderStage,
+            max_uniform_buffers_per_shader_stage = MaxUniformBuffersPerShaderStage,
+            max_binding_array_elements_per_shader_stage = MaxBindingArrayElementsPerShaderStage,
+            max_binding_array_sampler_elements_per_shader_stage = MaxBindingArraySamplerElementsPerShaderStage,
+            max_uniform_buffer_binding_size = MaxUniformBufferBindingSize,
+            max_storage_buffer_binding_size = MaxStorageBufferBindingSize,
+            max_vertex_buffers = MaxVertexBuffers,
+            max_buffer_size = MaxBufferSize,
+            max_vertex_attributes = MaxVertexAttributes,
+            max_vertex_buffer_array_stride = MaxVertexBufferArrayStride,
+            min_uniform_buffer_offset_alignment = MinUniformBufferOffsetAlignment,
+            min_storage_buffer_offset_alignment = MinStorageBufferOffsetAlignment,
+            max_inter_stage_shader_components = MaxInterStageShaderComponents,
+            max_color_attachments = MaxColorAttachments,
+            max_color_attachment_bytes_per_sample = MaxColorAttachmentBytesPerSample,
+            max_compute_workgroup_storage_size = MaxComputeWorkgroupStorageSize,
+            max_compute_invocations_per_workgroup = MaxComputeInvocationsPerWorkgroup,
+            max_compute_workgroup_size_x = MaxComputeWorkgroupSizeX,
+            max_compute_workgroup_size_y = MaxComputeWorkgroupSizeY,
+            max_compute_workgroup_size_z = MaxComputeWorkgroupSizeZ,
+            max_compute_workgroups_per_dimension = MaxComputeWorkgroupsPerDimension,
+            min_subgroup_size = MinSubgroupSize,
+            max_subgroup_size = MaxSubgroupSize,
+            max_push_constant_size = MaxPushConstantSize,
+            max_non_sampler_bindings = MaxNonSamplerBindings,
+        };
+    }
+
+    internal static GpuLimits FromNative(in Wgpu.Limits limits)
+    {
+        return new GpuLimits
+        {
+            MaxTextureDimension1D = limits.max_texture_dimension_1d,
+            MaxTextureDimension2D = limits.max_texture_dimension_2d,
+            MaxTextureDimension3D = limits.max_texture_dimension_3d,
+            MaxTextureArrayLayers = limits.max_texture_array_layers,
+            MaxBindGroups = limits.max_bind_groups,
+            MaxBindingsPerBindGroup = limits.max_bindings_per_bind_group,
+            MaxDynamicUniformBuffersPerPipelineLayout = limits.max_dynamic_uniform_buffers_per_pipeline_layout,
+            MaxDynamicStorageBuffersPerPipelineLayout = limits.max_dynamic_storage_buffers_per_pipeline_layout,
+            MaxSampledTexturesPerShaderStage = limits.max_sampled_textures_per_shader_stage,
+            MaxSamplersPerShaderStage = limits.max_samplers_per_shader_stage,
+            MaxStorageBuffersPerShaderStage = limits.max_storage_buffers_per_shader_stage,
+            MaxStorageTexturesPerShaderStage = limits.max_storage_textures_per_shader_stage,
+            MaxUniformBuffersPerShaderStage = limits.max_uniform_buffers_per_shader_stage,
+            MaxBindingArrayElementsPerShaderStage = limits.max_binding_array_elements_per_shader_stage,
+            MaxBindingArraySamplerElementsPerShaderStage = limits.max_binding_array_sampler_elements_per_shader_stage,
+            MaxUniformBufferBindingSize = limits.max_uniform_buffer_binding_size,
+            MaxStorageBufferBindingSize = limits.max_storage_buffer_binding_size,
+            MaxVertexBuffers = limits.max_vertex_buffers,
+            MaxBufferSize = limits.max_buffer_size,
+            MaxVertexAttributes = limits.max_vertex_attributes,
+            MaxVertexBufferArrayStride = limits.max_vertex_buffer_array_stride,
+            MinUniformBufferOffsetAlignment = limits.min_uniform_buffer_offset_alignment,
+            MinStorageBufferOffsetAlignment = limits.min_storage_buffer_offset_alignment,
+            MaxInterStageShaderComponents = limits.max_inter_stage_shader_components,
+            MaxColorAttachments = limits.max_color_attachments,
+            MaxColorAttachmentBytesPerSample = limits.max_color_attachment_bytes_per_sample,
+            MaxComputeWorkgroupStorageSize = limits.max_compute_workgroup_storage_size,
+            MaxComputeInvocationsPerWorkgroup = limits.max_compute_invocations_per_workgroup,
+            MaxComputeWorkgroupSizeX = limits.max_compute_workgroup_size_x,
+            MaxComputeWorkgroupSizeY = limits.max_compute_workgroup_size_y,
+            MaxComputeWorkgroupSizeZ = limits.max_compute_workgroup_size_z,
+            MaxComputeWorkgroupsPerDimension = limits.max_compute_workgroups_per_dimension,
+            MinSubgroupSize = limits.min_subgroup_size,
+            MaxSubgroupSize = limits.max_subgroup_size,
+            MaxPushConstantSize = limits.max_push_constant_size,
+            MaxNonSamplerBindings = limits.max_non_sampler_bindings,
+        };
+    }
+}
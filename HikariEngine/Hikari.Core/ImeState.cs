@@ -17,6 +17,8 @@ internal unsafe sealed class ImeState : IImePreeditState
     private int _len;
     private Range? _cursorBufRange;
     private Range? _cursorStringRange;
+    private Vector2i _cursorAreaPosition = new Vector2i(10, 10);
+    private Vector2u _cursorAreaSize = new Vector2u(30, 30);
 
     private Span<byte> BufferSpan => new Span<byte>(_buf, _bufCapacity);
     private ReadOnlySpan<byte> TextUtf8Span => new ReadOnlySpan<byte>(_buf, _len);
@@ -57,14 +59,28 @@ internal unsafe sealed class ImeState : IImePreeditState
         }
     }
 
+    public void SetCursorArea(Vector2i position, Vector2u size)
+    {
+        _cursorAreaPosition = position;
+        _cursorAreaSize = size;
+        _screen.AsRefChecked().SetImeCursorArea(position.X, position.Y, size.X, size.Y);
+    }
+
+    public void SetPurpose(ImePurpose purpose)
+    {
+        _screen.AsRefChecked().SetImePurpose(purpose.MapOrThrow());
+    }
+
     public void OnInput(in CH.ImeInputData input)
     {
         Range? range = input.range.TryGetValue(out var r) ? new Range(r.Start.ToInt32(), r.End.ToInt32()) : null;
+        Range? rangeUtf16 = input.range_utf16.TryGetValue(out var r16) ? new Range(r16.Start.ToInt32(), r16.End.ToInt32()) : null;
 
         var textUtf8 = new ReadOnlySpan<byte>(input.text.data, input.text.len.ToInt32());
 
         if(input.tag != CH.ImeInputData.Tag.Disabled) {
-            EngineCore.SetImePosition(_screen.AsRefChecked(), 10, 10);
+            var (pos, size) = (_cursorAreaPosition, _cursorAreaSize);
+            _screen.AsRefChecked().SetImeCursorArea(pos.X, pos.Y, size.X, size.Y);
         }
 
         switch(input.tag) {
@@ -78,14 +94,7 @@ internal unsafe sealed class ImeState : IImePreeditState
                 textUtf8.CopyTo(BufferSpan);
                 _len = textUtf8.Length;
                 _cursorBufRange = range;
-                if(range.HasValue) {
-                    int rangeStart = _utf8.GetCharCount(textUtf8[0..range.Value.Start]);
-                    int rangeLength = _utf8.GetCharCount(textUtf8[range.Value]);
-                    _cursorStringRange = new Range(rangeStart, rangeStart + rangeLength);
-                }
-                else {
-                    _cursorStringRange = null;
-                }
+                _cursorStringRange = rangeUtf16;
                 Preedit?.Invoke(this, _screen);
                 break;
             }
@@ -13,6 +13,10 @@ public sealed class Keyboard
     private bool[] _prevState;
     private KeyActionFlag[] _currentAction;
     private bool[] _currentState;
+    private EventSource<KeyInput> _keyInput;
+
+    /// <summary>Raised on every key press and release, including repeats and keys without <see cref="KeyCode"/>.</summary>
+    public Event<KeyInput> KeyInput => _keyInput.Event;
 
     internal Keyboard(Screen screen)
     {
@@ -26,11 +30,44 @@ public sealed class Keyboard
         _currentState = new bool[N];
     }
 
+    /// <summary>Set the area of the text cursor in physical pixels, which the IME candidate window avoids.</summary>
+    public void SetImeCursorArea(Vector2i position, Vector2u size)
+    {
+        _imeState.SetCursorArea(position, size);
+    }
+
+    /// <summary>Tell the IME what kind of text is input.</summary>
+    public void SetImePurpose(ImePurpose purpose)
+    {
+        _imeState.SetPurpose(purpose);
+    }
+
     internal void OnImeInput(in CH.ImeInputData input)
     {
         _imeState.OnInput(input);
     }
 
+    internal unsafe void OnKeyInput(in CH.KeyInputData input)
+    {
+        var utf8 = Encoding.UTF8;
+        _keyInput.Invoke(new KeyInput
+        {
+            Key = input.key.TryGetValue(out var key) ? key.MapOrThrow() : null,
+            Pressed = input.pressed,
+            Repeat = input.repeat,
+            Location = input.location.MapOrThrow(),
+            Modifiers = (KeyModifiers)input.modifiers,
+            LogicalKeyKind = input.logical_key_kind.MapOrThrow(),
+            LogicalKey = utf8.GetString(input.logical_key.data, input.logical_key.len.ToInt32()),
+            Text = utf8.GetString(input.text.data, input.text.len.ToInt32()),
+        });
+    }
+
+    internal void ClearEvents()
+    {
+        _keyInput.Clear();
+    }
+
     internal void OnCharReceived(Rune input)
     {
         if(Rune.IsValid(input.Value) == false) { return; }
@@ -94,6 +131,50 @@ public sealed class Keyboard
     }
 }
 
+public readonly record struct KeyInput
+{
+    /// <summary>physical key (null if the key is not supported)</summary>
+    public required KeyCode? Key { get; init; }
+    public required bool Pressed { get; init; }
+    public required bool Repeat { get; init; }
+    public required KeyLocation Location { get; init; }
+    public required KeyModifiers Modifiers { get; init; }
+    public required LogicalKeyKind LogicalKeyKind { get; init; }
+    /// <summary>
+    /// layout-dependent key
+    /// (the W3C key value for <see cref="LogicalKeyKind.Named"/>, e.g. "Enter", "ArrowLeft")
+    /// </summary>
+    public required string LogicalKey { get; init; }
+    /// <summary>text produced by the key with modifiers applied (empty if none)</summary>
+    public required string Text { get; init; }
+}
+
+public enum KeyLocation
+{
+    Standard = 0,
+    Left = 1,
+    Right = 2,
+    Numpad = 3,
+}
+
+public enum LogicalKeyKind
+{
+    Named = 0,
+    Character = 1,
+    Dead = 2,
+    Unidentified = 3,
+}
+
+[Flags]
+public enum KeyModifiers : uint
+{
+    None = 0,
+    Shift = 1 << 0,
+    Control = 1 << 1,
+    Alt = 1 << 2,
+    Super = 1 << 3,
+}
+
 [SequentialSet]
 public enum KeyCode
 {
@@ -0,0 +1,17 @@
+﻿#nullable enable
+
+namespace Hikari;
+
+/// <summary>Information of the monitor. (See <see cref="Screen.GetMonitorInfo(MonitorId)"/>)</summary>
+public readonly record struct MonitorInfo
+{
+    /// <summary>name of the monitor (null if it is unknown)</summary>
+    public required string? Name { get; init; }
+    /// <summary>physical position of the top-left corner in the desktop</summary>
+    public required Vector2i Position { get; init; }
+    /// <summary>physical size of the monitor</summary>
+    public required Vector2u Size { get; init; }
+    public required f64 ScaleFactor { get; init; }
+    /// <summary>refresh rate of the current video mode (null if it is unknown)</summary>
+    public required u32? RefreshRateMillihertz { get; init; }
+}
@@ -15,9 +15,17 @@ public sealed class Mouse
 
     private bool _isOnScreen;
     private WheelDeltaBuf _wheelDeltaBuf;
+    private EventSource<MouseScroll> _scrolled;
+    private EventSource<Vector2> _rawMotion;
 
     public Screen Screen => _screen;
 
+    /// <summary>Raised on every scroll of mouse wheels and touchpads, including the phases of touchpad gestures.</summary>
+    public Event<MouseScroll> Scrolled => _scrolled.Event;
+
+    /// <summary>Raised with raw (unaccelerated and unbounded) motion of the mouse while the screen is focused.</summary>
+    public Event<Vector2> RawMotion => _rawMotion.Event;
+
     public bool IsOnScreen => _isOnScreen;
 
     internal bool AreAnyButtonsChanged
@@ -65,6 +73,35 @@ public sealed class Mouse
         _screen = screen;
     }
 
+    /// <summary>Confine or lock the cursor. It throws if the platform does not support the mode.</summary>
+    public void SetCursorGrab(CursorGrabMode mode)
+    {
+        _screen.AsRefChecked().ScreenSetCursorGrab(mode.MapOrThrow());
+    }
+
+    public void SetCursorVisible(bool visible)
+    {
+        _screen.AsRefChecked().ScreenSetCursorVisible(visible);
+    }
+
+    public void SetCursorIcon(CursorIcon icon)
+    {
+        _screen.AsRefChecked().ScreenSetCursorIcon(icon.MapOrThrow());
+    }
+
+    /// <summary>Use the custom cursor image. The screen keeps the image even if the cursor is disposed.</summary>
+    public void SetCursor(CustomCursor cursor)
+    {
+        ArgumentNullException.ThrowIfNull(cursor);
+        _screen.AsRefChecked().ScreenSetCustomCursor(cursor.NativeRef);
+    }
+
+    /// <summary>Move the cursor to the position in physical pixels relative to the screen.</summary>
+    public void SetCursorPosition(Vector2 position)
+    {
+        _screen.AsRefChecked().ScreenSetCursorPosition(position.X, position.Y);
+    }
+
     [MethodImpl(MethodImplOptions.AggressiveInlining)]
     public bool IsChanged(MouseButton button)
     {
@@ -118,6 +155,27 @@ public sealed class Mouse
         }
     }
 
+    internal void OnScroll(in CH.MouseScrollData scroll)
+    {
+        _scrolled.Invoke(new MouseScroll
+        {
+            DeltaKind = scroll.delta_kind.MapOrThrow(),
+            Delta = new Vector2(scroll.x_delta, scroll.y_delta),
+            Phase = scroll.phase.MapOrThrow(),
+        });
+    }
+
+    internal void OnRawMotion(Vector2 delta)
+    {
+        _rawMotion.Invoke(delta);
+    }
+
+    internal void ClearEvents()
+    {
+        _scrolled.Clear();
+        _rawMotion.Clear();
+    }
+
     internal void OnCursorMoved(Vector2 pos)
     {
         lock(_sync) {
@@ -242,6 +300,14 @@ public sealed class Mouse
     }
 }
 
+public readonly record struct MouseScroll
+{
+    public required MouseScrollDeltaKind DeltaKind { get; init; }
+    /// <summary>lines (<see cref="MouseScrollDeltaKind.Line"/>) or physical pixels (<see cref="MouseScrollDeltaKind.Pixel"/>)</summary>
+    public required Vector2 Delta { get; init; }
+    public required TouchPhase Phase { get; init; }
+}
+
 public enum MouseButton : uint
 {
     Left = 0,
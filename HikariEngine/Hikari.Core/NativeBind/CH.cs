@@ -17,6 +17,7 @@ namespace Hikari.NativeBind;
 internal static class CH
 {
     internal sealed class Screen : INativeTypeNonReprC { private Screen() { } }
+    internal sealed class CustomCursor : INativeTypeNonReprC { private CustomCursor() { } }
 
     [StructLayout(LayoutKind.Sequential)]
     internal readonly struct Opt<T> where T : unmanaged
@@ -278,11 +279,34 @@ internal static class CH
         }
     }
 
+    /// <summary>non-generic <see cref="Slice{T}"/> of <see cref="u8"/> for arguments of callbacks</summary>
+    internal unsafe readonly struct SliceU8
+    {
+        public readonly u8* data;
+        public readonly usize len;
+
+        public ReadOnlySpan<u8> AsSpan() => new ReadOnlySpan<u8>(data, checked((int)len));
+    }
+
+    internal readonly struct AdapterInfo
+    {
+        public readonly SliceU8 name;
+        public readonly u32 vendor;
+        public readonly u32 device;
+        public readonly Wgpu.DeviceType device_type;
+        public readonly SliceU8 driver;
+        public readonly SliceU8 driver_info;
+        public readonly Wgpu.Backend backend;
+    }
+
     internal readonly struct ImeInputData
     {
         public readonly Tag tag;
         public readonly Slice<u8> text;
+        /// <summary>preedit cursor range in UTF-8 bytes</summary>
         public readonly Opt<RangeValue> range;
+        /// <summary>preedit cursor range in UTF-16 code units</summary>
+        public readonly Opt<RangeValue> range_utf16;
 
         internal enum Tag : u32
         {
@@ -295,14 +319,157 @@ internal static class CH
         internal record struct RangeValue(usize Start, usize End);
     }
 
+    internal enum ImePurpose : u32
+    {
+        [EnumMapTo(Hikari.ImePurpose.Normal)] Normal = 0,
+        [EnumMapTo(Hikari.ImePurpose.Password)] Password = 1,
+        [EnumMapTo(Hikari.ImePurpose.Terminal)] Terminal = 2,
+    }
+
+    internal readonly struct KeyInputData
+    {
+        public readonly Opt<KeyCode> key;
+        public readonly bool pressed;
+        public readonly bool repeat;
+        public readonly KeyLocation location;
+        public readonly Modifiers modifiers;
+        public readonly LogicalKeyKind logical_key_kind;
+        public readonly Slice<u8> logical_key;
+        public readonly Slice<u8> text;
+    }
+
+    internal enum KeyLocation : u32
+    {
+        [EnumMapTo(Hikari.KeyLocation.Standard)] Standard = 0,
+        [EnumMapTo(Hikari.KeyLocation.Left)] Left = 1,
+        [EnumMapTo(Hikari.KeyLocation.Right)] Right = 2,
+        [EnumMapTo(Hikari.KeyLocation.Numpad)] Numpad = 3,
+    }
+
+    [Flags]
+    internal enum Modifiers : u32
+    {
+        SHIFT = 1 << 0,
+        CONTROL = 1 << 1,
+        ALT = 1 << 2,
+        SUPER = 1 << 3,
+    }
+
+    internal enum LogicalKeyKind : u32
+    {
+        [EnumMapTo(Hikari.LogicalKeyKind.Named)] Named = 0,
+        [EnumMapTo(Hikari.LogicalKeyKind.Character)] Character = 1,
+        [EnumMapTo(Hikari.LogicalKeyKind.Dead)] Dead = 2,
+        [EnumMapTo(Hikari.LogicalKeyKind.Unidentified)] Unidentified = 3,
+    }
+
+    internal readonly struct MouseScrollData
+    {
+        public readonly MouseScrollDeltaKind delta_kind;
+        public readonly f32 x_delta;
+        public readonly f32 y_delta;
+        public readonly TouchPhase phase;
+    }
+
+    internal readonly struct TouchData
+    {
+        public readonly u64 id;
+        public readonly TouchPhase phase;
+        public readonly f32 x;
+        public readonly f32 y;
+        public readonly Opt<f32> force;
+    }
+
+    internal enum MouseScrollDeltaKind : u32
+    {
+        [EnumMapTo(Hikari.MouseScrollDeltaKind.Line)] Line = 0,
+        [EnumMapTo(Hikari.MouseScrollDeltaKind.Pixel)] Pixel = 1,
+    }
+
+    internal enum TouchPhase : u32
+    {
+        [EnumMapTo(Hikari.TouchPhase.Started)] Started = 0,
+        [EnumMapTo(Hikari.TouchPhase.Moved)] Moved = 1,
+        [EnumMapTo(Hikari.TouchPhase.Ended)] Ended = 2,
+        [EnumMapTo(Hikari.TouchPhase.Cancelled)] Cancelled = 3,
+    }
+
+    internal enum CursorGrabMode : u32
+    {
+        None = 0,
+        Confined = 1,
+        Locked = 2,
+    }
+
+    internal enum CursorIcon : u32
+    {
+        Default = 0,
+        ContextMenu = 1,
+        Help = 2,
+        Pointer = 3,
+        Progress = 4,
+        Wait = 5,
+        Cell = 6,
+        Crosshair = 7,
+        Text = 8,
+        VerticalText = 9,
+        Alias = 10,
+        Copy = 11,
+        Move = 12,
+        NoDrop = 13,
+        NotAllowed = 14,
+        Grab = 15,
+        Grabbing = 16,
+        EResize = 17,
+        NResize = 18,
+        NeResize = 19,
+        NwResize = 20,
+        SResize = 21,
+        SeResize = 22,
+        SwResize = 23,
+        WResize = 24,
+        EwResize = 25,
+        NsResize = 26,
+        NeswResize = 27,
+        NwseResize = 28,
+        ColResize = 29,
+        RowResize = 30,
+        AllScroll = 31,
+        ZoomIn = 32,
+        ZoomOut = 33,
+    }
+
+    internal enum Theme : u32
+    {
+        [EnumMapTo(Hikari.ScreenTheme.System)] System = 0,
+        [EnumMapTo(Hikari.ScreenTheme.Light)] Light = 1,
+        [EnumMapTo(Hikari.ScreenTheme.Dark)] Dark = 2,
+    }
+
+    internal enum CompositeAlphaMode : u32
+    {
+        [EnumMapTo(Hikari.SurfaceAlphaMode.Auto)] Auto = 0,
+        [EnumMapTo(Hikari.SurfaceAlphaMode.Opaque)] Opaque = 1,
+        [EnumMapTo(Hikari.SurfaceAlphaMode.PreMultiplied)] PreMultiplied = 2,
+        [EnumMapTo(Hikari.SurfaceAlphaMode.PostMultiplied)] PostMultiplied = 3,
+        [EnumMapTo(Hikari.SurfaceAlphaMode.Inherit)] Inherit = 4,
+    }
+
     internal enum PresentMode : u32
     {
         AutoVsync = 0,
         AutoNoVsync = 1,
-        Fifo = 2,
-        FifoRelaxed = 3,
-        Immediate = 4,
-        Mailbox = 5,
+        [EnumMapTo(Hikari.SurfacePresentMode.VsyncOn)] Fifo = 2,
+        [EnumMapTo(Hikari.SurfacePresentMode.AdaptiveVsync)] FifoRelaxed = 3,
+        [EnumMapTo(Hikari.SurfacePresentMode.VsyncOff)] Immediate = 4,
+        [EnumMapTo(Hikari.SurfacePresentMode.FastVsync)] Mailbox = 5,
+    }
+
+    internal readonly struct SurfaceCapabilities
+    {
+        public readonly Slice<TextureFormat> formats;
+        public readonly Slice<PresentMode> present_modes;
+        public readonly Slice<CompositeAlphaMode> alpha_modes;
     }
 
     internal enum WindowStyle
@@ -329,6 +496,26 @@ internal static class CH
         public required ClosingEventFn event_closing;
         public required ClosedEventFn event_closed;
         public required DebugPrintlnFn debug_println;
+        public required MouseScrollEventFn event_mouse_scroll;
+        public required ScaleFactorChangedEventFn event_scale_factor_changed;
+        public required TouchEventFn event_touch;
+        public required KeyInputEventFn event_key_input;
+        public required RawMouseMotionEventFn event_raw_mouse_motion;
+        public required FileHoveredEventFn event_file_hovered;
+        public required FileDroppedEventFn event_file_dropped;
+        public required FileHoverCancelledEventFn event_file_hover_cancelled;
+        public required FocusedEventFn event_focused;
+        public required OccludedEventFn event_occluded;
+        public required ExitPolicy exit_policy;
+        public required ThemeChangedEventFn event_theme_changed;
+        public required DeviceLostEventFn event_device_lost;
+    }
+
+    internal enum ExitPolicy : u32
+    {
+        LastScreenClosed = 0,
+        MainScreenClosed = 1,
+        Explicit = 2,
     }
 
     internal struct ScreenConfig
@@ -338,12 +525,67 @@ internal static class CH
         public required u32 height;
         public required Wgpu.Backends backend;
         public required CH.PresentMode present_mode;
+        public required Opt<OffscreenConfig> offscreen;
+        public required bool throttle_when_hidden;
+        public required Opt<IconImage> icon;
+        public required bool transparent;
+        public required CompositeAlphaMode alpha_mode;
+        public required Slice<u8> title;
+        public required Opt<Tuple<i32, i32>> position;
+        public required Opt<MonitorId> monitor;
+        public required bool hidden;
+        public required Wgpu.PowerPreference power_preference;
+        public required Opt<usize> adapter_index;
+        public required bool force_fallback_adapter;
+        public required Features required_features;
+        public required Features optional_features;
+        public required Opt<Wgpu.Limits> limits;
+        public required bool use_adapter_limits;
+        public required Slice<TextureFormat> preferred_surface_formats;
+    }
+
+    internal struct Features
+    {
+        public required u64 wgpu;
+        public required u64 webgpu;
+    }
+
+    internal struct OffscreenConfig
+    {
+        public required u32 frame_interval_micros;
+    }
+
+    internal struct IconImage
+    {
+        public required Slice<u8> rgba;
+        public required u32 width;
+        public required u32 height;
     }
 
     internal struct ScreenInfo
     {
         public required Wgpu.Backend backend;
         public required Opt<TextureFormat> surface_format;
+        public required CompositeAlphaMode alpha_mode;
+    }
+
+    internal readonly struct MonitorInfo
+    {
+        public readonly usize name_len;
+        public readonly i32 x;
+        public readonly i32 y;
+        public readonly u32 width;
+        public readonly u32 height;
+        public readonly f64 scale_factor;
+        public readonly Opt<u32> refresh_rate_millihertz;
+    }
+
+    internal struct VideoMode
+    {
+        public u32 width;
+        public u32 height;
+        public u16 bit_depth;
+        public u32 refresh_rate_millihertz;
     }
 
     internal readonly struct MonitorId : IEquatable<MonitorId>
@@ -401,12 +643,25 @@ internal static class CH
         public ResizedEventFn(delegate* unmanaged[Cdecl]<ScreenId, u32, u32, void> f) => _func = f;
     }
 
+    internal unsafe readonly struct ScaleFactorChangedEventFn
+    {
+        private readonly delegate* unmanaged[Cdecl]<ScreenId, f64, SizeU32*, void> _func;
+
+        public ScaleFactorChangedEventFn(delegate* unmanaged[Cdecl]<ScreenId, f64, SizeU32*, void> f) => _func = f;
+    }
+
     internal unsafe readonly struct KeyboardEventFn
     {
         private readonly delegate* unmanaged[Cdecl]<ScreenId, CH.KeyCode, bool, void> _func;
         public KeyboardEventFn(delegate* unmanaged[Cdecl]<ScreenId, CH.KeyCode, bool, void> f) => _func = f;
     }
 
+    internal unsafe readonly struct KeyInputEventFn
+    {
+        private readonly delegate* unmanaged[Cdecl]<ScreenId, KeyInputData*, void> _func;
+        public KeyInputEventFn(delegate* unmanaged[Cdecl]<ScreenId, KeyInputData*, void> f) => _func = f;
+    }
+
     internal unsafe readonly struct CharReceivedEventFn
     {
         private readonly delegate* unmanaged[Cdecl]<ScreenId, Rune, void> _func;
@@ -431,6 +686,12 @@ internal static class CH
         public MouseWheelEventFn(delegate* unmanaged[Cdecl]<ScreenId, f32, f32, void> f) => _func = f;
     }
 
+    internal unsafe readonly struct RawMouseMotionEventFn
+    {
+        private readonly delegate* unmanaged[Cdecl]<ScreenId, f64, f64, void> _func;
+        public RawMouseMotionEventFn(delegate* unmanaged[Cdecl]<ScreenId, f64, f64, void> f) => _func = f;
+    }
+
     internal unsafe readonly struct CursorMovedEventFn
     {
         private readonly delegate* unmanaged[Cdecl]<ScreenId, f32, f32, void> _func;
@@ -443,6 +704,60 @@ internal static class CH
         public CursorEnteredLeftEventFn(delegate* unmanaged[Cdecl]<ScreenId, bool, void> f) => _func = f;
     }
 
+    internal unsafe readonly struct MouseScrollEventFn
+    {
+        private readonly delegate* unmanaged[Cdecl]<ScreenId, MouseScrollData*, void> _func;
+        public MouseScrollEventFn(delegate* unmanaged[Cdecl]<ScreenId, MouseScrollData*, void> f) => _func = f;
+    }
+
+    internal unsafe readonly struct TouchEventFn
+    {
+        private readonly delegate* unmanaged[Cdecl]<ScreenId, TouchData*, void> _func;
+        public TouchEventFn(delegate* unmanaged[Cdecl]<ScreenId, TouchData*, void> f) => _func = f;
+    }
+
+    internal unsafe readonly struct FileHoveredEventFn
+    {
+        private readonly delegate* unmanaged[Cdecl]<ScreenId, SliceU8, f32, f32, void> _func;
+        public FileHoveredEventFn(delegate* unmanaged[Cdecl]<ScreenId, SliceU8, f32, f32, void> f) => _func = f;
+    }
+
+    internal unsafe readonly struct FileDroppedEventFn
+    {
+        private readonly delegate* unmanaged[Cdecl]<ScreenId, SliceU8, f32, f32, void> _func;
+        public FileDroppedEventFn(delegate* unmanaged[Cdecl]<ScreenId, SliceU8, f32, f32, void> f) => _func = f;
+    }
+
+    internal unsafe readonly struct FileHoverCancelledEventFn
+    {
+        private readonly delegate* unmanaged[Cdecl]<ScreenId, void> _func;
+        public FileHoverCancelledEventFn(delegate* unmanaged[Cdecl]<ScreenId, void> f) => _func = f;
+    }
+
+    internal unsafe readonly struct FocusedEventFn
+    {
+        private readonly delegate* unmanaged[Cdecl]<ScreenId, bool, void> _func;
+        public FocusedEventFn(delegate* unmanaged[Cdecl]<ScreenId, bool, void> f) => _func = f;
+    }
+
+    internal unsafe readonly struct OccludedEventFn
+    {
+        private readonly delegate* unmanaged[Cdecl]<ScreenId, bool, void> _func;
+        public OccludedEventFn(delegate* unmanaged[Cdecl]<ScreenId, bool, void> f) => _func = f;
+    }
+
+    internal unsafe readonly struct DeviceLostEventFn
+    {
+        private readonly delegate* unmanaged[Cdecl]<ScreenId, Wgpu.DeviceLostReason, SliceU8, void> _func;
+        public DeviceLostEventFn(delegate* unmanaged[Cdecl]<ScreenId, Wgpu.DeviceLostReason, SliceU8, void> f) => _func = f;
+    }
+
+    internal unsafe readonly struct ThemeChangedEventFn
+    {
+        private readonly delegate* unmanaged[Cdecl]<ScreenId, Theme, void> _func;
+        public ThemeChangedEventFn(delegate* unmanaged[Cdecl]<ScreenId, Theme, void> f) => _func = f;
+    }
+
     internal unsafe readonly struct ClosingEventFn
     {
         private readonly delegate* unmanaged[Cdecl]<ScreenId, bool*, void> _func;
@@ -322,6 +322,68 @@ internal static class Wgpu
         INDIRECT = 1 << 8,
     }
 
+    internal struct Limits
+    {
+        public required u32 max_texture_dimension_1d;
+        public required u32 max_texture_dimension_2d;
+        public required u32 max_texture_dimension_3d;
+        public required u32 max_texture_array_layers;
+        public required u32 max_bind_groups;
+        public required u32 max_bindings_per_bind_group;
+        public required u32 max_dynamic_uniform_buffers_per_pipeline_layout;
+        public required u32 max_dynamic_storage_buffers_per_pipeline_layout;
+        public required u32 max_sampled_textures_per_shader_stage;
+        public required u32 max_samplers_per_shader_stage;
+        public required u32 max_storage_buffers_per_shader_stage;
+        public required u32 max_storage_textures_per_shader_stage;
+        public required u32 max_uniform_buffers_per_shader_stage;
+        public required u32 max_binding_array_elements_per_shader_stage;
+        public required u32 max_binding_array_sampler_elements_per_shader_stage;
+        public required u32 max_uniform_buffer_binding_size;
+        public required u32 max_storage_buffer_binding_size;
+        public required u32 max_vertex_buffers;
+        public required u64 max_buffer_size;
+        public required u32 max_vertex_attributes;
+        public required u32 max_vertex_buffer_array_stride;
+        public required u32 min_uniform_buffer_offset_alignment;
+        public required u32 min_storage_buffer_offset_alignment;
+        public required u32 max_inter_stage_shader_components;
+        public required u32 max_color_attachments;
+        public required u32 max_color_attachment_bytes_per_sample;
+        public required u32 max_compute_workgroup_storage_size;
+        public required u32 max_compute_invocations_per_workgroup;
+        public required u32 max_compute_workgroup_size_x;
+        public required u32 max_compute_workgroup_size_y;
+        public required u32 max_compute_workgroup_size_z;
+        public required u32 max_compute_workgroups_per_dimension;
+        public required u32 min_subgroup_size;
+        public required u32 max_subgroup_size;
+        public required u32 max_push_constant_size;
+        public required u32 max_non_sampler_bindings;
+    }
+
+    internal enum DeviceLostReason : u8
+    {
+        [EnumMapTo(GpuDeviceLostReason.Unknown)] Unknown = 0,
+        [EnumMapTo(GpuDeviceLostReason.Destroyed)] Destroyed = 1,
+    }
+
+    internal enum DeviceType : u8
+    {
+        [EnumMapTo(GpuDeviceType.Other)] Other = 0,
+        [EnumMapTo(GpuDeviceType.IntegratedGpu)] IntegratedGpu = 1,
+        [EnumMapTo(GpuDeviceType.DiscreteGpu)] DiscreteGpu = 2,
+        [EnumMapTo(GpuDeviceType.VirtualGpu)] VirtualGpu = 3,
+        [EnumMapTo(GpuDeviceType.Cpu)] Cpu = 4,
+    }
+
+    internal enum PowerPreference : u32
+    {
+        None = 0,
+        LowPower = 1,
+        HighPerformance = 2,
+    }
+
     internal enum Backend : u8
     {
         [EnumMapTo(GraphicsBackend.None)] Empty = 0,
@@ -1,5 +1,6 @@
 ﻿#nullable enable
 using Hikari.NativeBind;
+using System;
 
 namespace Hikari;
 
@@ -38,6 +39,22 @@ public enum GraphicsBackend : byte
     BrowserWebGpu = 6,
 }
 
+public enum MouseScrollDeltaKind
+{
+    /// <summary>mouse wheel</summary>
+    Line = 0,
+    /// <summary>touchpad</summary>
+    Pixel = 1,
+}
+
+public enum TouchPhase
+{
+    Started = 0,
+    Moved = 1,
+    Ended = 2,
+    Cancelled = 3,
+}
+
 public enum SurfacePresentMode
 {
     [EnumMapTo(CH.PresentMode.Fifo)]
@@ -49,3 +66,256 @@ public enum SurfacePresentMode
     [EnumMapTo(CH.PresentMode.Mailbox)]
     FastVsync = 3,
 }
+
+public enum EngineExitPolicy
+{
+    /// <summary>Exit when the last screen is closed.</summary>
+    [EnumMapTo(CH.ExitPolicy.LastScreenClosed)]
+    LastScreenClosed = 0,
+    /// <summary>Exit when the first created screen is closed.</summary>
+    [EnumMapTo(CH.ExitPolicy.MainScreenClosed)]
+    MainScreenClosed = 1,
+    /// <summary>Exit only by <see cref="Engine.Exit"/>.</summary>
+    [EnumMapTo(CH.ExitPolicy.Explicit)]
+    Explicit = 2,
+}
+
+public enum ScreenTheme
+{
+    /// <summary>Follow the theme of the system.</summary>
+    [EnumMapTo(CH.Theme.System)]
+    System = 0,
+    [EnumMapTo(CH.Theme.Light)]
+    Light = 1,
+    [EnumMapTo(CH.Theme.Dark)]
+    Dark = 2,
+}
+
+public enum GpuPowerPreference
+{
+    /// <summary>Power usage is not considered when choosing an adapter.</summary>
+    [EnumMapTo(Wgpu.PowerPreference.None)]
+    None = 0,
+    /// <summary>Adapter that uses the least possible power, which is often an integrated GPU.</summary>
+    [EnumMapTo(Wgpu.PowerPreference.LowPower)]
+    LowPower = 1,
+    /// <summary>Adapter that has the highest performance, which is often a discrete GPU.</summary>
+    [EnumMapTo(Wgpu.PowerPreference.HighPerformance)]
+    HighPerformance = 2,
+}
+
+public enum GpuDeviceType
+{
+    /// <summary>Other or unknown</summary>
+    Other = 0,
+    /// <summary>Integrated GPU with shared CPU/GPU memory</summary>
+    IntegratedGpu = 1,
+    /// <summary>Discrete GPU with separate CPU/GPU memory</summary>
+    DiscreteGpu = 2,
+    /// <summary>Virtual or hosted GPU</summary>
+    VirtualGpu = 3,
+    /// <summary>CPU (software rendering)</summary>
+    Cpu = 4,
+}
+
+/// <summary>Native features of the GPU, which is the same as <c>wgpu::FeaturesWGPU</c></summary>
+[Flags]
+public enum GpuFeatures : u64
+{
+    None = 0,
+    ShaderFloat32Atomic = 1uL << 0,
+    TextureFormat16BitNorm = 1uL << 1,
+    TextureCompressionAstcHdr = 1uL << 2,
+    TextureAdapterSpecificFormatFeatures = 1uL << 3,
+    PipelineStatisticsQuery = 1uL << 4,
+    TimestampQueryInsideEncoders = 1uL << 5,
+    TimestampQueryInsidePasses = 1uL << 6,
+    MappablePrimaryBuffers = 1uL << 7,
+    TextureBindingArray = 1uL << 8,
+    BufferBindingArray = 1uL << 9,
+    StorageResourceBindingArray = 1uL << 10,
+    SampledTextureAndStorageBufferArrayNonUniformIndexing = 1uL << 11,
+    StorageTextureArrayNonUniformIndexing = 1uL << 12,
+    PartiallyBoundBindingArray = 1uL << 13,
+    MultiDrawIndirect = 1uL << 14,
+    MultiDrawIndirectCount = 1uL << 15,
+    PushConstants = 1uL << 16,
+    AddressModeClampToZero = 1uL << 17,
+    AddressModeClampToBorder = 1uL << 18,
+    PolygonModeLine = 1uL << 19,
+    PolygonModePoint = 1uL << 20,
+    ConservativeRasterization = 1uL << 21,
+    VertexWritableStorage = 1uL << 22,
+    ClearTexture = 1uL << 23,
+    MslShaderPassthrough = 1uL << 24,
+    SpirvShaderPassthrough = 1uL << 25,
+    Multiview = 1uL << 26,
+    VertexAttribute64Bit = 1uL << 27,
+    TextureAtomic = 1uL << 28,
+    TextureFormatNv12 = 1uL << 29,
+    ExperimentalRayTracingAccelerationStructure = 1uL << 30,
+    ExperimentalRayQuery = 1uL << 31,
+    ShaderF64 = 1uL << 32,
+    ShaderI16 = 1uL << 33,
+    ShaderPrimitiveIndex = 1uL << 34,
+    ShaderEarlyDepthTest = 1uL << 35,
+    ShaderInt64 = 1uL << 36,
+    Subgroup = 1uL << 37,
+    SubgroupVertex = 1uL << 38,
+    SubgroupBarrier = 1uL << 39,
+    PipelineCache = 1uL << 40,
+    ShaderInt64AtomicMinMax = 1uL << 41,
+    ShaderInt64AtomicAllOps = 1uL << 42,
+    VulkanGoogleDisplayTiming = 1uL << 43,
+    VulkanExternalMemoryWin32 = 1uL << 44,
+    TextureInt64Atomic = 1uL << 45,
+    UniformBufferBindingArrays = 1uL << 46,
+    ExperimentalMeshShader = 1uL << 47,
+    ExperimentalRayHitVertexReturn = 1uL << 48,
+    ExperimentalMeshShaderMultiview = 1uL << 49,
+}
+
+/// <summary>WebGPU features of the GPU, which is the same as <c>wgpu::FeaturesWebGPU</c></summary>
+[Flags]
+public enum GpuWebFeatures : u64
+{
+    None = 0,
+    DepthClipControl = 1uL << 0,
+    Depth32FloatStencil8 = 1uL << 1,
+    TextureCompressionBc = 1uL << 2,
+    TextureCompressionBcSliced3D = 1uL << 3,
+    TextureCompressionEtc2 = 1uL << 4,
+    TextureCompressionAstc = 1uL << 5,
+    TimestampQuery = 1uL << 6,
+    IndirectFirstInstance = 1uL << 7,
+    ShaderF16 = 1uL << 8,
+    Rg11b10UfloatRenderable = 1uL << 9,
+    Bgra8UnormStorage = 1uL << 10,
+    Float32Filterable = 1uL << 11,
+    DualSourceBlending = 1uL << 12,
+}
+
+public enum GpuDeviceLostReason : byte
+{
+    /// <summary>Lost by the driver (e.g. the GPU is removed or reset)</summary>
+    [EnumMapTo(Wgpu.DeviceLostReason.Unknown)]
+    Unknown = 0,
+    /// <summary>Lost because the device is destroyed</summary>
+    [EnumMapTo(Wgpu.DeviceLostReason.Destroyed)]
+    Destroyed = 1,
+}
+
+public enum SurfaceAlphaMode
+{
+    /// <summary>The engine chooses the mode supported by the surface.</summary>
+    [EnumMapTo(CH.CompositeAlphaMode.Auto)]
+    Auto = 0,
+    /// <summary>The alpha channel is ignored.</summary>
+    [EnumMapTo(CH.CompositeAlphaMode.Opaque)]
+    Opaque = 1,
+    /// <summary>The color channels are expected to be multiplied by the alpha channel.</summary>
+    [EnumMapTo(CH.CompositeAlphaMode.PreMultiplied)]
+    PreMultiplied = 2,
+    /// <summary>The color channels are multiplied by the alpha channel when compositing.</summary>
+    [EnumMapTo(CH.CompositeAlphaMode.PostMultiplied)]
+    PostMultiplied = 3,
+    /// <summary>The mode is set by the native window system.</summary>
+    [EnumMapTo(CH.CompositeAlphaMode.Inherit)]
+    Inherit = 4,
+}
+
+public enum ImePurpose
+{
+    [EnumMapTo(CH.ImePurpose.Normal)]
+    Normal = 0,
+    /// <summary>The IME hides the input, and may disable itself.</summary>
+    [EnumMapTo(CH.ImePurpose.Password)]
+    Password = 1,
+    /// <summary>The IME is used for terminal input.</summary>
+    [EnumMapTo(CH.ImePurpose.Terminal)]
+    Terminal = 2,
+}
+
+public enum CursorGrabMode
+{
+    [EnumMapTo(CH.CursorGrabMode.None)]
+    None = 0,
+    /// <summary>The cursor is confined to the screen. (not supported on macOS)</summary>
+    [EnumMapTo(CH.CursorGrabMode.Confined)]
+    Confined = 1,
+    /// <summary>The cursor is locked at its position. (not supported on Windows and X11)</summary>
+    [EnumMapTo(CH.CursorGrabMode.Locked)]
+    Locked = 2,
+}
+
+public enum CursorIcon
+{
+    [EnumMapTo(CH.CursorIcon.Default)]
+    Default = 0,
+    [EnumMapTo(CH.CursorIcon.ContextMenu)]
+    ContextMenu = 1,
+    [EnumMapTo(CH.CursorIcon.Help)]
+    Help = 2,
+    [EnumMapTo(CH.CursorIcon.Pointer)]
+    Pointer = 3,
+    [EnumMapTo(CH.CursorIcon.Progress)]
+    Progress = 4,
+    [EnumMapTo(CH.CursorIcon.Wait)]
+    Wait = 5,
+    [EnumMapTo(CH.CursorIcon.Cell)]
+    Cell = 6,
+    [EnumMapTo(CH.CursorIcon.Crosshair)]
+    Crosshair = 7,
+    [EnumMapTo(CH.CursorIcon.Text)]
+    Text = 8,
+    [EnumMapTo(CH.CursorIcon.VerticalText)]
+    VerticalText = 9,
+    [EnumMapTo(CH.CursorIcon.Alias)]
+    Alias = 10,
+    [EnumMapTo(CH.CursorIcon.Copy)]
+    Copy = 11,
+    [EnumMapTo(CH.CursorIcon.Move)]
+    Move = 12,
+    [EnumMapTo(CH.CursorIcon.NoDrop)]
+    NoDrop = 13,
+    [EnumMapTo(CH.CursorIcon.NotAllowed)]
+    NotAllowed = 14,
+    [EnumMapTo(CH.CursorIcon.Grab)]
+    Grab = 15,
+    [EnumMapTo(CH.CursorIcon.Grabbing)]
+    Grabbing = 16,
+    [EnumMapTo(CH.CursorIcon.EResize)]
+    EResize = 17,
+    [EnumMapTo(CH.CursorIcon.NResize)]
+    NResize = 18,
+    [EnumMapTo(CH.CursorIcon.NeResize)]
+    NeResize = 19,
+    [EnumMapTo(CH.CursorIcon.NwResize)]
+    NwResize = 20,
+    [EnumMapTo(CH.CursorIcon.SResize)]
+    SResize = 21,
+    [EnumMapTo(CH.CursorIcon.SeResize)]
+    SeResize = 22,
+    [EnumMapTo(CH.CursorIcon.SwResize)]
+    SwResize = 23,
+    [EnumMapTo(CH.CursorIcon.WResize)]
+    WResize = 24,
+    [EnumMapTo(CH.CursorIcon.EwResize)]
+    EwResize = 25,
+    [EnumMapTo(CH.CursorIcon.NsResize)]
+    NsResize = 26,
+    [EnumMapTo(CH.CursorIcon.NeswResize)]
+    NeswResize = 27,
+    [EnumMapTo(CH.CursorIcon.NwseResize)]
+    NwseResize = 28,
+    [EnumMapTo(CH.CursorIcon.ColResize)]
+    ColResize = 29,
+    [EnumMapTo(CH.CursorIcon.RowResize)]
+    RowResize = 30,
+    [EnumMapTo(CH.CursorIcon.AllScroll)]
+    AllScroll = 31,
+    [EnumMapTo(CH.CursorIcon.ZoomIn)]
+    ZoomIn = 32,
+    [EnumMapTo(CH.CursorIcon.ZoomOut)]
+    ZoomOut = 33,
+}
@@ -3,6 +3,7 @@ using Hikari.NativeBind;
 using Hikari.UI;
 using System;
 using System.Buffers;
+using System.Collections.Immutable;
 using System.Diagnostics;
 using System.Diagnostics.CodeAnalysis;
 using System.Runtime.CompilerServices;
@@ -30,6 +31,7 @@ public sealed class Screen
     private readonly Timing _destroyObjectInternal;
     private readonly SyncContextReceiver? _syncContextreceiver;
     private GraphicsBackend _backend;
+    private SurfaceAlphaMode _alphaMode;
     private bool _initialized;
     private string _title = "";
     private readonly Mouse _mouse;
@@ -46,6 +48,15 @@ public sealed class Screen
     private EventSource<ScreenClosingState> _closing;
     private EventSource<Screen> _closed;
     private EventSource<(Screen Screen, Vector2u Size)> _resized;
+    private EventSource<ScaleFactorChangedState> _scaleFactorChanged;
+    private EventSource<(Screen Screen, Touch Touch)> _touched;
+    private EventSource<(Screen Screen, string Path, Vector2 Position)> _fileHovered;
+    private EventSource<(Screen Screen, string Path, Vector2 Position)> _fileDropped;
+    private EventSource<Screen> _fileHoverCancelled;
+    private EventSource<(Screen Screen, bool Focused)> _focusChanged;
+    private EventSource<(Screen Screen, bool Occluded)> _occlusionChanged;
+    private EventSource<(Screen Screen, ScreenTheme Theme)> _themeChanged;
+    private EventSource<(Screen Screen, GpuDeviceLostReason Reason, string Message)> _deviceLost;
 
     internal enum RunningState
     {
@@ -69,6 +80,20 @@ public sealed class Screen
     public Event<ScreenClosingState> Closing => _closing.Event;
     public Event<Screen> Closed => _closed.Event;
     public Event<(Screen Screen, Vector2u Size)> Resized => _resized.Event;
+    public Event<ScaleFactorChangedState> ScaleFactorChanged => _scaleFactorChanged.Event;
+    public Event<(Screen Screen, Touch Touch)> Touched => _touched.Event;
+    /// <summary>Raised for each file dragged onto the screen. (the position is the last known cursor position)</summary>
+    public Event<(Screen Screen, string Path, Vector2 Position)> FileHovered => _fileHovered.Event;
+    /// <summary>Raised for each file dropped onto the screen. (the position is the last known cursor position)</summary>
+    public Event<(Screen Screen, string Path, Vector2 Position)> FileDropped => _fileDropped.Event;
+    public Event<Screen> FileHoverCancelled => _fileHoverCancelled.Event;
+    public Event<(Screen Screen, bool Focused)> FocusChanged => _focusChanged.Event;
+    /// <summary>Raised when the screen is fully hidden by other windows or shown again. (not supported on some platforms)</summary>
+    public Event<(Screen Screen, bool Occluded)> OcclusionChanged => _occlusionChanged.Event;
+    /// <summary>Raised when the theme of the window is changed. (the theme is never <see cref="ScreenTheme.System"/>)</summary>
+    public Event<(Screen Screen, ScreenTheme Theme)> ThemeChanged => _themeChanged.Event;
+    /// <summary>Raised on the main thread when the GPU device is lost. (Recreate it by <see cref="RecreateDevice"/>.)</summary>
+    public Event<(Screen Screen, GpuDeviceLostReason Reason, string Message)> DeviceLost => _deviceLost.Event;
 
     internal CH.ScreenId ScreenId => new CH.ScreenId(_native.Unwrap());
     public ThreadId MainThread => _mainThread;
@@ -105,6 +130,46 @@ public sealed class Screen
         }
     }
 
+    /// <summary>alpha mode chosen for the surface</summary>
+    public SurfaceAlphaMode AlphaMode
+    {
+        get
+        {
+            ThrowIfNotInit();
+            return _alphaMode;
+        }
+    }
+
+    /// <summary>features enabled on the device (updated by <see cref="RecreateDevice"/>)</summary>
+    public GpuFeatures Features
+    {
+        get
+        {
+            ThrowIfNotInit();
+            return (GpuFeatures)_native.Unwrap().AsRef().ScreenGetFeatures().wgpu;
+        }
+    }
+
+    /// <summary>WebGPU features enabled on the device (updated by <see cref="RecreateDevice"/>)</summary>
+    public GpuWebFeatures WebFeatures
+    {
+        get
+        {
+            ThrowIfNotInit();
+            return (GpuWebFeatures)_native.Unwrap().AsRef().ScreenGetFeatures().webgpu;
+        }
+    }
+
+    /// <summary>limits of the device (updated by <see cref="RecreateDevice"/>)</summary>
+    public GpuLimits Limits
+    {
+        get
+        {
+            ThrowIfNotInit();
+            return GpuLimits.FromNative(_native.Unwrap().AsRef().ScreenGetLimits());
+        }
+    }
+
     public Vector2u ClientSize
     {
         get
@@ -154,10 +219,161 @@ public sealed class Screen
         }
     }
 
+    /// <summary>Change the icon of the window. (ignored by offscreen screens)</summary>
+    public void SetIcon(ScreenIcon icon)
+    {
+        ThrowIfNotInit();
+        _native.Unwrap().AsRef().ScreenSetIcon(icon.Rgba.Span, icon.Width, icon.Height);
+    }
+
+    /// <summary>Get the current theme of the window. (null if it is unknown)</summary>
+    public ScreenTheme? GetTheme()
+    {
+        ThrowIfNotInit();
+        return _native.Unwrap().AsRef().ScreenGetTheme().TryGetValue(out var theme) ? theme.MapOrThrow() : null;
+    }
+
+    /// <summary>Set the theme of the window. <see cref="ScreenTheme.System"/> follows the system theme.</summary>
+    public void SetTheme(ScreenTheme theme)
+    {
+        ThrowIfNotInit();
+        _native.Unwrap().AsRef().ScreenSetTheme(theme.MapOrThrow());
+    }
+
+    /// <summary>
+    /// Recreate the GPU device and the surface. (e.g. after <see cref="DeviceLost"/>)<br/>
+    /// All GPU objects created from the old device must be recreated.
+    /// If it fails, the screen has no surface to render to until it succeeds by calling this again.
+    /// </summary>
+    public void RecreateDevice()
+    {
+        _mainThread.ThrowIfNotMatched();
+        ThrowIfNotInit();
+        var info = _native.Unwrap().AsRef().ScreenRecreateDevice();
+        _backend = info.backend.MapOrThrow();
+        _alphaMode = info.alpha_mode.MapOrThrow();
+    }
+
+    /// <summary>
+    /// Close the screen in the event loop. <see cref="Closing"/> is raised as well as the close button of the window.
+    /// The engine exits according to <see cref="EngineExitPolicy"/>.
+    /// </summary>
+    public void Close()
+    {
+        ThrowIfNotInit();
+        _native.Unwrap().AsRef().ScreenClose();
+    }
+
+    public SurfaceCapabilities GetSurfaceCapabilities()
+    {
+        ThrowIfNotInit();
+        return _native.Unwrap().AsRef().ScreenGetSurfaceCapabilities();
+    }
+
+    /// <summary>
+    /// Switch the present mode. (e.g. VSync on/off)
+    /// It throws if the surface does not support the mode (See <see cref="GetSurfaceCapabilities"/>), or the screen is offscreen.
+    /// </summary>
+    public void SetPresentMode(SurfacePresentMode presentMode)
+    {
+        ThrowIfNotInit();
+        _native.Unwrap().AsRef().ScreenSetPresentMode(presentMode.MapOrThrow());
+    }
+
+    public void SetVisible(bool visible)
+    {
+        ThrowIfNotInit();
+        _native.Unwrap().AsRef().ScreenSetVisible(visible);
+    }
+
+    /// <summary>Set the minimum client size in physical pixels. (null means no limit)</summary>
+    public void SetMinClientSize(Vector2u? size)
+    {
+        ThrowIfNotInit();
+        _native.Unwrap().AsRef().ScreenSetMinInnerSize(size);
+    }
+
+    /// <summary>Set the maximum client size in physical pixels. (null means no limit)</summary>
+    public void SetMaxClientSize(Vector2u? size)
+    {
+        ThrowIfNotInit();
+        _native.Unwrap().AsRef().ScreenSetMaxInnerSize(size);
+    }
+
+    public bool IsMaximized
+    {
+        get
+        {
+            ThrowIfNotInit();
+            return _native.Unwrap().AsRef().ScreenIsMaximized();
+        }
+    }
+
+    /// <summary>Whether the window is minimized. (null if the platform cannot tell it, e.g. Wayland)</summary>
+    public bool? IsMinimized
+    {
+        get
+        {
+            ThrowIfNotInit();
+            return _native.Unwrap().AsRef().ScreenIsMinimized();
+        }
+    }
+
+    public void Maximize()
+    {
+        ThrowIfNotInit();
+        _native.Unwrap().AsRef().ScreenSetMaximized(true);
+    }
+
+    public void Minimize()
+    {
+        ThrowIfNotInit();
+        _native.Unwrap().AsRef().ScreenSetMinimized(true);
+    }
+
+    /// <summary>Restore the window from the minimized or maximized state.</summary>
+    public void Restore()
+    {
+        ThrowIfNotInit();
+        _native.Unwrap().AsRef().ScreenRestore();
+    }
+
+    public bool IsAlwaysOnTop
+    {
+        get
+        {
+            ThrowIfNotInit();
+            return _native.Unwrap().AsRef().ScreenIsAlwaysOnTop();
+        }
+        set
+        {
+            ThrowIfNotInit();
+            _native.Unwrap().AsRef().ScreenSetAlwaysOnTop(value);
+        }
+    }
+
+    /// <summary>Whether the window has the title bar and borders.</summary>
+    public bool IsDecorated
+    {
+        get
+        {
+            ThrowIfNotInit();
+            return _native.Unwrap().AsRef().ScreenIsDecorated();
+        }
+        set
+        {
+            ThrowIfNotInit();
+            _native.Unwrap().AsRef().ScreenSetDecorations(value);
+        }
+    }
+
     public uint MonitorCount => _native.Unwrap().AsRef().MonitorCount().ToUInt32();
 
     public MonitorId? CurrentMonitor => _native.Unwrap().AsRef().CurrentMonitor();
 
+    /// <summary>primary monitor of the system (null if the platform has no primary monitor, e.g. Wayland)</summary>
+    public MonitorId? PrimaryMonitor => _native.Unwrap().AsRef().PrimaryMonitor();
+
     public Camera Camera => _camera;
     public Lights Lights => _lights;
 
@@ -246,9 +462,48 @@ public sealed class Screen
         return monitors;
     }
 
+    public MonitorInfo GetMonitorInfo(MonitorId monitorId)
+    {
+        _mainThread.ThrowIfNotMatched();
+        return monitorId.Id.GetInfo();
+    }
+
+    /// <summary>Get the video modes of the monitor, which can be used for <see cref="SetExclusiveFullscreen"/>.</summary>
+    public VideoMode[] GetVideoModes(MonitorId monitorId)
+    {
+        _mainThread.ThrowIfNotMatched();
+        var count = monitorId.Id.VideoModeCount().ToUInt32();
+        if(count == 0) {
+            return Array.Empty<VideoMode>();
+        }
+        var buf = new CH.VideoMode[count];
+        var len = monitorId.Id.VideoModes(buf).ToUInt32();
+        var videoModes = new VideoMode[len];
+        for(int i = 0; i < videoModes.Length; i++) {
+            videoModes[i] = VideoMode.FromNative(buf[i]);
+        }
+        return videoModes;
+    }
+
+    /// <summary>Change the style of the window. (<see cref="WindowStyle.Fullscreen"/> is the borderless fullscreen.)</summary>
+    public void SetStyle(WindowStyle style)
+    {
+        ThrowIfNotInit();
+        _native.Unwrap().AsRef().ScreenSetStyle(style.MapOrThrow());
+    }
+
+    /// <summary>Make the window fullscreen exclusively. The video mode must be one of <see cref="GetVideoModes(MonitorId)"/>.</summary>
+    public void SetExclusiveFullscreen(MonitorId monitorId, in VideoMode videoMode)
+    {
+        _mainThread.ThrowIfNotMatched();
+        ThrowIfNotInit();
+        _native.Unwrap().AsRef().ScreenSetExclusiveFullscreen(monitorId.Id, videoMode.ToNative());
+    }
+
     internal void OnInitialize(in CH.ScreenInfo info)
     {
         _backend = info.backend.MapOrThrow();
+        _alphaMode = info.alpha_mode.MapOrThrow();
 
         var size = ClientSize;
         _depthStencil = RenderTextureProvider.Create(this, new()
@@ -387,6 +642,59 @@ public sealed class Screen
         _resized.Invoke((this, size));
     }
 
+    internal void OnScaleFactorChanged(float scaleFactor, ref Vector2u innerSize)
+    {
+        var arg = new ScaleFactorChangedState(this, scaleFactor, innerSize);
+        _scaleFactorChanged.Invoke(arg);
+        innerSize = arg.InnerSize;
+    }
+
+    internal void OnTouch(in CH.TouchData touch)
+    {
+        _touched.Invoke((this, new Touch
+        {
+            Id = touch.id,
+            Phase = touch.phase.MapOrThrow(),
+            Position = new Vector2(touch.x, touch.y),
+            Force = touch.force.GetOrNull(),
+        }));
+    }
+
+    internal void OnFileHovered(string path, Vector2 position)
+    {
+        _fileHovered.Invoke((this, path, position));
+    }
+
+    internal void OnFileDropped(string path, Vector2 position)
+    {
+        _fileDropped.Invoke((this, path, position));
+    }
+
+    internal void OnFileHoverCancelled()
+    {
+        _fileHoverCancelled.Invoke(this);
+    }
+
+    internal void OnFocused(bool focused)
+    {
+        _focusChanged.Invoke((this, focused));
+    }
+
+    internal void OnOccluded(bool occluded)
+    {
+        _occlusionChanged.Invoke((this, occluded));
+    }
+
+    internal void OnThemeChanged(ScreenTheme theme)
+    {
+        _themeChanged.Invoke((this, theme));
+    }
+
+    internal void OnDeviceLost(GpuDeviceLostReason reason, string message)
+    {
+        _deviceLost.Invoke((this, reason, message));
+    }
+
     internal void OnClosing(ref bool cancel)
     {
         Debug.Assert(_mainThread.IsCurrentThread);
@@ -423,6 +731,17 @@ public sealed class Screen
         _depthStencil = Own<RenderTextureProvider>.None;
         _lights.DisposeInternal();
         _resized.Clear();
+        _scaleFactorChanged.Clear();
+        _touched.Clear();
+        _fileHovered.Clear();
+        _fileDropped.Clear();
+        _fileHoverCancelled.Clear();
+        _focusChanged.Clear();
+        _occlusionChanged.Clear();
+        _themeChanged.Clear();
+        _deviceLost.Clear();
+        _mouse.ClearEvents();
+        _keyboard.ClearEvents();
         _subscriptions.Dispose();
         _utilResource.DisposeInternal();
         _info.Dispose();
@@ -472,6 +791,44 @@ public sealed class ScreenClosingState
     }
 }
 
+public readonly record struct Touch
+{
+    /// <summary>unique identifier of a finger, which is the same from <see cref="TouchPhase.Started"/> to <see cref="TouchPhase.Ended"/> or <see cref="TouchPhase.Cancelled"/></summary>
+    public required u64 Id { get; init; }
+    public required TouchPhase Phase { get; init; }
+    /// <summary>physical position in the screen</summary>
+    public required Vector2 Position { get; init; }
+    /// <summary>normalized force (0.0 ~ 1.0) if the device supports it</summary>
+    public required float? Force { get; init; }
+}
+
+public sealed class ScaleFactorChangedState
+{
+    private readonly Screen _screen;
+    private readonly float _scaleFactor;
+    private Vector2u _innerSize;
+
+    public Screen Screen => _screen;
+    public float ScaleFactor => _scaleFactor;
+
+    /// <summary>
+    /// The suggested new inner size, which keeps the logical size of the screen.
+    /// Set another size to request it, or zero to leave it to the OS.
+    /// </summary>
+    public Vector2u InnerSize
+    {
+        get => _innerSize;
+        set => _innerSize = value;
+    }
+
+    internal ScaleFactorChangedState(Screen screen, float scaleFactor, Vector2u innerSize)
+    {
+        _screen = screen;
+        _scaleFactor = scaleFactor;
+        _innerSize = innerSize;
+    }
+}
+
 public readonly record struct ScreenConfig
 {
     public required WindowStyle Style { get; init; }
@@ -480,12 +837,55 @@ public readonly record struct ScreenConfig
     public required GraphicsBackend Backend { get; init; }
     public required SurfacePresentMode PresentMode { get; init; }
     public bool UseSynchronizationContext { get; init; } = true;
+    /// <summary>Create the screen without a window, which is redrawn at this interval. (null means a window screen)</summary>
+    public TimeSpan? OffscreenFrameInterval { get; init; }
+    /// <summary>Skip redrawing while the screen is minimized, occluded or hidden.</summary>
+    public bool ThrottleWhenHidden { get; init; }
+    /// <summary>Icon of the window. (null means the default icon)</summary>
+    public ScreenIcon? Icon { get; init; }
+    /// <summary>Make the background of the window transparent. (The surface needs a non-opaque <see cref="AlphaMode"/>.)</summary>
+    public bool Transparent { get; init; }
+    /// <summary>Preferred alpha mode of the surface, which falls back to a supported mode.</summary>
+    public SurfaceAlphaMode AlphaMode { get; init; } = SurfaceAlphaMode.Auto;
+    /// <summary>Initial title of the window</summary>
+    public string Title { get; init; } = "";
+    /// <summary>
+    /// Initial position of the window relative to <see cref="Monitor"/>. (null means the center of the monitor)<br/>
+    /// It is the position in the desktop if no monitor is found.
+    /// </summary>
+    public Vector2i? Position { get; init; }
+    /// <summary>Monitor to place the window on. (null means the current monitor)</summary>
+    public MonitorId? Monitor { get; init; }
+    /// <summary>Create the window hidden, which is shown by <see cref="Screen.SetVisible(bool)"/>.</summary>
+    public bool Hidden { get; init; }
+    public GpuPowerPreference PowerPreference { get; init; } = GpuPowerPreference.None;
+    /// <summary>Index of the adapter enumerated with <see cref="Backend"/>. (null means the adapter is chosen by <see cref="PowerPreference"/>)</summary>
+    public int? AdapterIndex { get; init; }
+    /// <summary>Use the software adapter. (e.g. for testing)</summary>
+    public bool ForceFallbackAdapter { get; init; }
+    /// <summary>Creating the screen fails if the adapter does not support them.</summary>
+    public GpuFeatures RequiredFeatures { get; init; }
+    /// <summary>Creating the screen fails if the adapter does not support them.</summary>
+    public GpuWebFeatures RequiredWebFeatures { get; init; }
+    /// <summary>Enabled only if the adapter supports them.</summary>
+    public GpuFeatures OptionalFeatures { get; init; }
+    /// <summary>Enabled only if the adapter supports them.</summary>
+    public GpuWebFeatures OptionalWebFeatures { get; init; }
+    /// <summary>Required limits of the device. (null means the default limits)</summary>
+    public GpuLimits? Limits { get; init; }
+    /// <summary>Request the best limits of the adapter instead of <see cref="Limits"/>.</summary>
+    public bool UseAdapterLimits { get; init; }
+    /// <summary>
+    /// Surface formats in order of preference. (e.g. <see cref="TextureFormat.Rgba16Float"/> for HDR)<br/>
+    /// The first sRGB format is used if none of them is supported. (<see cref="Surface.Format"/> is the chosen one.)
+    /// </summary>
+    public ImmutableArray<TextureFormat> PreferredSurfaceFormats { get; init; } = ImmutableArray<TextureFormat>.Empty;
 
     public ScreenConfig()
     {
     }
 
-    internal CH.ScreenConfig ToCoreType()
+    internal CH.ScreenConfig ToCoreType(PinHandleHolder pins)
     {
         return new CH.ScreenConfig
         {
@@ -494,10 +894,53 @@ public readonly record struct ScreenConfig
             height = Height,
             backend = Backend.MapOrThrow(),
             present_mode = PresentMode.MapOrThrow(),
+            offscreen = OffscreenFrameInterval.ToNative(static interval => new CH.OffscreenConfig
+            {
+                frame_interval_micros = checked((u32)(interval.Ticks / TimeSpan.TicksPerMicrosecond)),
+            }),
+            throttle_when_hidden = ThrottleWhenHidden,
+            icon = Icon.ToNative(icon => new CH.IconImage
+            {
+                rgba = icon.Rgba.AsFixedSlice(pins),
+                width = icon.Width,
+                height = icon.Height,
+            }),
+            transparent = Transparent,
+            alpha_mode = AlphaMode.MapOrThrow(),
+            title = Encoding.UTF8.GetBytes(Title).AsFixedSlice(pins),
+            position = Position.ToNative(static position => new CH.Tuple<i32, i32>(position.X, position.Y)),
+            monitor = CH.Opt.From(Monitor?.Id),
+            hidden = Hidden,
+            power_preference = PowerPreference.MapOrThrow(),
+            adapter_index = AdapterIndex.ToNative(static index => checked((usize)index)),
+            force_fallback_adapter = ForceFallbackAdapter,
+            required_features = new CH.Features
+            {
+                wgpu = (u64)RequiredFeatures,
+                webgpu = (u64)RequiredWebFeatures,
+            },
+            optional_features = new CH.Features
+            {
+                wgpu = (u64)OptionalFeatures,
+                webgpu = (u64)OptionalWebFeatures,
+            },
+            limits = Limits.ToNative(static limits => limits.ToNative()),
+            use_adapter_limits = UseAdapterLimits,
+            preferred_surface_formats = PreferredSurfaceFormats.IsDefault
+                ? CH.Slice<CH.TextureFormat>.Empty
+                : PreferredSurfaceFormats.SelectToArray(static f => f.MapOrThrow()).AsFixedSlice(pins),
         };
     }
 }
 
+public readonly record struct ScreenIcon
+{
+    /// <summary>pixels of the icon in RGBA8 format, whose length is <see cref="Width"/> * <see cref="Height"/> * 4</summary>
+    public required ReadOnlyMemory<u8> Rgba { get; init; }
+    public required u32 Width { get; init; }
+    public required u32 Height { get; init; }
+}
+
 public readonly struct MonitorId : IEquatable<MonitorId>
 {
     private readonly CH.MonitorId _id;
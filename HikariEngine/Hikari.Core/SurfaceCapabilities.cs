@@ -0,0 +1,13 @@
+﻿#nullable enable
+using System.Collections.Immutable;
+
+namespace Hikari;
+
+/// <summary>Capabilities of the surface of the screen. (See <see cref="Screen.GetSurfaceCapabilities"/>)</summary>
+public readonly record struct SurfaceCapabilities
+{
+    /// <summary>supported formats (the formats not supported by <see cref="TextureFormat"/> are excluded)</summary>
+    public required ImmutableArray<TextureFormat> Formats { get; init; }
+    public required ImmutableArray<SurfacePresentMode> PresentModes { get; init; }
+    public required ImmutableArray<SurfaceAlphaMode> AlphaModes { get; init; }
+}
@@ -0,0 +1,34 @@
+﻿#nullable enable
+using Hikari.NativeBind;
+
+namespace Hikari;
+
+/// <summary>Video mode of the monitor for the exclusive fullscreen. (See <see cref="Screen.GetVideoModes(MonitorId)"/>)</summary>
+public readonly record struct VideoMode
+{
+    /// <summary>physical size of the video mode</summary>
+    public required Vector2u Size { get; init; }
+    public required u16 BitDepth { get; init; }
+    public required u32 RefreshRateMillihertz { get; init; }
+
+    internal static VideoMode FromNative(in CH.VideoMode videoMode)
+    {
+        return new VideoMode
+        {
+            Size = new Vector2u(videoMode.width, videoMode.height),
+            BitDepth = videoMode.bit_depth,
+            RefreshRateMillihertz = videoMode.refresh_rate_millihertz,
+        };
+    }
+
+    internal CH.VideoMode ToNative()
+    {
+        return new CH.VideoMode
+        {
+            width = Size.X,
+            height = Size.Y,
+            bit_depth = BitDepth,
+            refresh_rate_millihertz = RefreshRateMillihertz,
+        };
+    }
+}
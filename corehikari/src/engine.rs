@@ -3,18 +3,25 @@ use crate::*;
 use std::cell::Cell;
 use std::error::Error;
 use std::fmt::Debug;
+use std::path;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Mutex, RwLock};
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
 use winit;
 use winit::application::ApplicationHandler;
-use winit::event::{self, MouseScrollDelta, TouchPhase, WindowEvent};
+use winit::dpi::PhysicalSize;
+use winit::event::{self, DeviceEvent, DeviceId, MouseScrollDelta, TouchPhase, WindowEvent};
 use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop, EventLoopProxy};
+use winit::keyboard::ModifiersState;
 use winit::platform::run_on_demand::EventLoopExtRunOnDemand;
 use winit::window::{self, WindowId};
 
 pub(crate) struct Engine {
     config: EngineCoreConfig,
-    screens: Vec<ScreenIdData>,
+    screens: Vec<WindowScreenData>,
+    offscreens: Vec<OffscreenFrameTimer>,
+    main_screen: Option<ScreenId>,
+    exit_requested: bool,
 }
 
 thread_local! {
@@ -44,13 +51,27 @@ impl Engine {
         Engine {
             config: *config,
             screens: vec![],
+            offscreens: vec![],
+            main_screen: None,
+            exit_requested: false,
         }
     }
 
-    fn on_screen_init(&self, screen: Box<Screen>) -> ScreenId {
+    fn on_screen_init(&mut self, screen: Box<Screen>) -> ScreenId {
         let f = self.config.on_screen_init;
         let screen_info = &screen.get_info();
-        f(screen, screen_info)
+        let screen_id = f(screen, screen_info);
+        self.main_screen.get_or_insert(screen_id);
+        screen_id
+    }
+
+    fn on_device_lost(
+        &self,
+    ) -> impl Fn(ScreenId, wgpu::DeviceLostReason, &str) + Send + Sync + 'static {
+        let f = self.config.event_device_lost;
+        move |screen_id: ScreenId, reason: wgpu::DeviceLostReason, message: &str| {
+            f(screen_id, reason, Slice::new(message.as_bytes()))
+        }
     }
 
     fn on_unhandled_error(&self) -> impl Fn(&str) + Send + Sync + 'static {
@@ -76,11 +97,26 @@ impl Engine {
         f(screen_id, width, height)
     }
 
+    fn event_scale_factor_changed(
+        &self,
+        screen_id: ScreenId,
+        scale_factor: f64,
+        inner_size: &mut SizeU32,
+    ) {
+        let f = self.config.event_scale_factor_changed;
+        f(screen_id, scale_factor, inner_size)
+    }
+
     fn event_keyboard(&self, screen_id: ScreenId, key: crate::KeyCode, pressed: bool) {
         let f = self.config.event_keyboard;
         f(screen_id, key, pressed);
     }
 
+    fn event_key_input(&self, screen_id: ScreenId, input: &KeyInputData) {
+        let f = self.config.event_key_input;
+        f(screen_id, input)
+    }
+
     fn event_char_received(&self, screen_id: ScreenId, c: char) {
         let f = self.config.event_char_received;
         f(screen_id, c as u32)
@@ -110,6 +146,53 @@ impl Engine {
         f(screen_id, x_delta, y_delta)
     }
 
+    fn event_mouse_scroll(&self, screen_id: ScreenId, scroll: &MouseScrollData) {
+        let f = self.config.event_mouse_scroll;
+        f(screen_id, scroll)
+    }
+
+    fn event_touch(&self, screen_id: ScreenId, touch: &TouchData) {
+        let f = self.config.event_touch;
+        f(screen_id, touch)
+    }
+
+    fn event_raw_mouse_motion(&self, screen_id: ScreenId, x_delta: f64, y_delta: f64) {
+        let f = self.config.event_raw_mouse_motion;
+        f(screen_id, x_delta, y_delta)
+    }
+
+    fn event_file_hovered(&self, screen_id: ScreenId, path: &path::Path, position: (f32, f32)) {
+        let f = self.config.event_file_hovered;
+        let path = path.to_string_lossy();
+        f(
+            screen_id,
+            Slice::new(path.as_bytes()),
+            position.0,
+            position.1,
+        )
+    }
+
+    fn event_file_dropped(&self, screen_id: ScreenId, path: &path::Path, position: (f32, f32)) {
+        let f = self.config.event_file_dropped;
+        let path = path.to_string_lossy();
+        f(
+            screen_id,
+            Slice::new(path.as_bytes()),
+            position.0,
+            position.1,
+        )
+    }
+
+    fn event_file_hover_cancelled(&self, screen_id: ScreenId) {
+        let f = self.config.event_file_hover_cancelled;
+        f(screen_id)
+    }
+
+    fn event_theme_changed(&self, screen_id: ScreenId, theme: Theme) {
+        let f = self.config.event_theme_changed;
+        f(screen_id, theme)
+    }
+
     fn event_cursor_moved(&self, screen_id: ScreenId, x: f32, y: f32) {
         let f = self.config.event_cursor_moved;
         f(screen_id, x, y)
@@ -120,6 +203,16 @@ impl Engine {
         f(screen_id, entered);
     }
 
+    fn event_focused(&self, screen_id: ScreenId, focused: bool) {
+        let f = self.config.event_focused;
+        f(screen_id, focused)
+    }
+
+    fn event_occluded(&self, screen_id: ScreenId, occluded: bool) {
+        let f = self.config.event_occluded;
+        f(screen_id, occluded)
+    }
+
     fn event_closing(&self, screen_id: ScreenId) -> bool {
         let f = self.config.event_closing;
         let mut cancel = false;
@@ -132,46 +225,281 @@ impl Engine {
         f(screen_id)
     }
 
-    fn close_screen(&mut self, target: &ScreenIdData) -> bool {
-        if self.event_closing(target.1) {
-            let index = self.screens.iter().position(|x| x == target);
-            if let Some(index) = index {
-                self.screens.swap_remove(index);
-            }
-            let is_empty = self.screens.is_empty();
-            let closed_screen = self.event_closed(target.1);
+    /// Returns true if the engine should exit.
+    fn close_screen(&mut self, screen_id: ScreenId) -> bool {
+        if self.event_closing(screen_id) {
+            self.screens.retain(|x| x.screen_id != screen_id);
+            self.offscreens.retain(|x| x.screen_id != screen_id);
+            let closed_screen = self.event_closed(screen_id);
             drop(closed_screen);
-            is_empty
+            match self.config.exit_policy {
+                ExitPolicy::LastScreenClosed => self.is_empty(),
+                ExitPolicy::MainScreenClosed => self.main_screen == Some(screen_id),
+                ExitPolicy::Explicit => false,
+            }
         } else {
             false
         }
     }
-}
 
-impl ApplicationHandler<ProxyMessage> for Engine {
-    fn resumed(&mut self, _event_loop: &ActiveEventLoop) {}
+    /// Close all remaining screens without `event_closing`, which cannot be canceled.
+    fn close_all_screens(&mut self) {
+        let screen_ids: SmallVec<[ScreenId; 4]> = self
+            .screens
+            .iter()
+            .map(|x| x.screen_id)
+            .chain(self.offscreens.iter().map(|x| x.screen_id))
+            .collect();
+        self.screens.clear();
+        self.offscreens.clear();
+        for screen_id in screen_ids {
+            let closed_screen = self.event_closed(screen_id);
+            drop(closed_screen);
+        }
+    }
 
-    fn user_event(&mut self, event_loop: &ActiveEventLoop, event: ProxyMessage) {
+    fn find_screen_id(&self, screen_serial: u64) -> Option<ScreenId> {
+        self.screens
+            .iter()
+            .map(|x| (x.screen_serial, x.screen_id))
+            .chain(
+                self.offscreens
+                    .iter()
+                    .map(|x| (x.screen_serial, x.screen_id)),
+            )
+            .find(|x| x.0 == screen_serial)
+            .map(|x| x.1)
+    }
+
+    fn window_screen_mut(&mut self, screen_id: ScreenId) -> Option<&mut WindowScreenData> {
+        self.screens.iter_mut().find(|x| x.screen_id == screen_id)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.screens.is_empty() && self.offscreens.is_empty()
+    }
+
+    /// Stop polling if all screens are hidden (or no screens exist) and they need not to be redrawn.
+    fn control_flow(&self) -> ControlFlow {
+        let all_throttled = self.offscreens.is_empty()
+            && self
+                .screens
+                .iter()
+                .all(|x| x.throttle_when_hidden && x.visibility.is_hidden());
+        match all_throttled {
+            true => ControlFlow::Wait,
+            false => ControlFlow::Poll,
+        }
+    }
+
+    fn create_screen(
+        &mut self,
+        config: &OwnedScreenConfig,
+        event_loop: Option<&ActiveEventLoop>,
+    ) -> Result<(), Box<dyn Error>> {
+        match config.offscreen.to_option() {
+            Some(offscreen) => {
+                let screen = Box::new(Screen::new_offscreen(
+                    config,
+                    self.on_unhandled_error(),
+                    self.on_device_lost(),
+                )?);
+                let screen_serial = screen.serial();
+                let device_lost = screen.device_lost_notifier();
+                let screen_id = self.on_screen_init(screen);
+                device_lost.set_screen_id(screen_id);
+                self.offscreens.push(OffscreenFrameTimer::new(
+                    screen_id,
+                    screen_serial,
+                    &offscreen,
+                ));
+            }
+            None => {
+                let event_loop = event_loop.ok_or(EngineErr::NO_DISPLAY)?;
+                let screen = Box::new(Screen::new(
+                    config,
+                    event_loop,
+                    self.on_unhandled_error(),
+                    self.on_device_lost(),
+                )?);
+                let window = screen.window_arc()?;
+                let visibility = screen.visibility();
+                let screen_serial = screen.serial();
+                let device_lost = screen.device_lost_notifier();
+                let screen_id = self.on_screen_init(screen);
+                device_lost.set_screen_id(screen_id);
+                self.screens.push(WindowScreenData {
+                    scale_factor: window.scale_factor(),
+                    modifiers: ModifiersState::empty(),
+                    cursor_position: (0.0, 0.0),
+                    visibility,
+                    throttle_when_hidden: config.throttle_when_hidden,
+                    window,
+                    screen_id,
+                    screen_serial,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_proxy_message(&mut self, event_loop: Option<&ActiveEventLoop>, event: ProxyMessage) {
         match event {
             ProxyMessage::CreateScreen(config) => {
-                let screen = match Screen::new(&config, event_loop, self.on_unhandled_error()) {
-                    Ok(screen) => screen,
-                    Err(_err) => {
-                        return;
+                if let Err(err) = self.create_screen(&config, event_loop) {
+                    debug_println!("[corehikari] failed to create screen: {}", err);
+                    (self.on_unhandled_error())(&format!("failed to create screen: {}", err));
+                    if self.main_screen.is_none() {
+                        // the first screen could not be created, so nothing can run.
+                        self.exit_requested = true;
                     }
-                };
-                let screen = Box::new(screen);
-                let window_id = screen.window.id();
-                let screen_id = self.on_screen_init(screen);
-                self.screens.push(ScreenIdData(window_id, screen_id));
+                }
+            }
+            ProxyMessage::RunOnMainThread(task) => {
+                task.run();
+            }
+            ProxyMessage::CloseScreen(screen_serial) => {
+                // The screen may be already closed.
+                if let Some(screen_id) = self.find_screen_id(screen_serial) {
+                    if self.close_screen(screen_id) {
+                        self.exit_requested = true;
+                    }
+                }
+            }
+            ProxyMessage::Exit => {
+                self.exit_requested = true;
+            }
+            ProxyMessage::SetCustomCursor(window, cursor) => {
+                // Custom cursors can only be created in the running event loop.
+                if let Some(event_loop) = event_loop {
+                    window.set_cursor(cursor.get_or_create(event_loop));
+                }
+            }
+        }
+    }
+
+    /// Raise redraw events of offscreen screens whose frame time has come.
+    /// Returns true if the engine should exit.
+    fn redraw_offscreens(&mut self, now: Instant) -> bool {
+        let targets: SmallVec<[ScreenId; 4]> = self
+            .offscreens
+            .iter_mut()
+            .filter(|x| x.next_frame <= now)
+            .map(|x| {
+                x.next_frame = (x.next_frame + x.interval).max(now);
+                x.screen_id
+            })
+            .collect();
+        let mut exit = false;
+        for screen_id in targets {
+            if !self.event_redraw_requested(screen_id) {
+                exit |= self.close_screen(screen_id);
             }
         }
+        exit
     }
 
-    fn about_to_wait(&mut self, _event_loop: &ActiveEventLoop) {
+    fn next_offscreen_frame(&self) -> Option<Instant> {
+        self.offscreens.iter().map(|x| x.next_frame).min()
+    }
+
+    /// Run the loop without the window system, which drives only offscreen screens.
+    fn run_headless(&mut self, receiver: mpsc::Receiver<ProxyMessage>) {
+        loop {
+            while let Ok(message) = receiver.try_recv() {
+                self.handle_proxy_message(None, message);
+            }
+            if self.exit_requested {
+                break;
+            }
+            if self.is_empty() && self.config.exit_policy != ExitPolicy::Explicit {
+                break;
+            }
+            if self.redraw_offscreens(Instant::now()) {
+                break;
+            }
+            self.offscreens.iter().for_each(|x| {
+                self.event_cleared(x.screen_id);
+            });
+            let message = match self.next_offscreen_frame() {
+                Some(next_frame) => {
+                    let timeout = next_frame.saturating_duration_since(Instant::now());
+                    receiver.recv_timeout(timeout).ok()
+                }
+                // No screens to redraw. Wait for the next message. (e.g. `hikari_engine_exit`)
+                None => match receiver.recv() {
+                    Ok(message) => Some(message),
+                    Err(_) => break,
+                },
+            };
+            if let Some(message) = message {
+                self.handle_proxy_message(None, message);
+            }
+        }
+        self.close_all_screens();
+    }
+}
+
+struct OffscreenFrameTimer {
+    screen_id: ScreenId,
+    screen_serial: u64,
+    interval: Duration,
+    next_frame: Instant,
+}
+
+impl OffscreenFrameTimer {
+    fn new(screen_id: ScreenId, screen_serial: u64, config: &OffscreenConfig) -> Self {
+        Self {
+            screen_id,
+            screen_serial,
+            interval: Duration::from_micros(config.frame_interval_micros.into()),
+            next_frame: Instant::now(),
+        }
+    }
+}
+
+impl ApplicationHandler<ProxyMessage> for Engine {
+    fn resumed(&mut self, _event_loop: &ActiveEventLoop) {}
+
+    fn user_event(&mut self, event_loop: &ActiveEventLoop, event: ProxyMessage) {
+        self.handle_proxy_message(Some(event_loop), event);
+        if self.exit_requested {
+            event_loop.exit();
+        }
+    }
+
+    fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
+        self.close_all_screens();
+    }
+
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        if self.redraw_offscreens(Instant::now()) {
+            event_loop.exit();
+            return;
+        }
         self.screens.iter().for_each(|x| {
-            self.event_cleared(x.1);
+            self.event_cleared(x.screen_id);
+        });
+        self.offscreens.iter().for_each(|x| {
+            self.event_cleared(x.screen_id);
         });
+        event_loop.set_control_flow(self.control_flow());
+    }
+
+    fn device_event(
+        &mut self,
+        _event_loop: &ActiveEventLoop,
+        _device_id: DeviceId,
+        event: DeviceEvent,
+    ) {
+        if let DeviceEvent::MouseMotion { delta } = event {
+            self.screens
+                .iter()
+                .filter(|x| x.window.has_focus())
+                .for_each(|x| {
+                    self.event_raw_mouse_motion(x.screen_id, delta.0, delta.1);
+                });
+        }
     }
 
     fn window_event(
@@ -180,53 +508,85 @@ impl ApplicationHandler<ProxyMessage> for Engine {
         window_id: WindowId,
         event: WindowEvent,
     ) {
-        let target = match self.screens.iter().find(|x| x.0 == window_id).copied() {
-            Some(target) => target,
-            None => {
-                return;
-            }
-        };
+        let (screen_id, window, modifiers, cursor_position) =
+            match self.screens.iter().find(|x| x.window.id() == window_id) {
+                Some(target) => (
+                    target.screen_id,
+                    target.window.clone(),
+                    target.modifiers,
+                    target.cursor_position,
+                ),
+                None => {
+                    return;
+                }
+            };
         match event {
             WindowEvent::CursorEntered { .. } => {
-                self.event_cursor_entered_left(target.1, true);
+                self.event_cursor_entered_left(screen_id, true);
             }
             WindowEvent::CursorLeft { .. } => {
-                self.event_cursor_entered_left(target.1, false);
+                self.event_cursor_entered_left(screen_id, false);
             }
             WindowEvent::CursorMoved { position, .. } => {
-                self.event_cursor_moved(target.1, position.x as f32, position.y as f32);
+                let position = (position.x as f32, position.y as f32);
+                if let Some(target) = self.window_screen_mut(screen_id) {
+                    target.cursor_position = position;
+                }
+                self.event_cursor_moved(screen_id, position.0, position.1);
+            }
+            WindowEvent::HoveredFile(path) => {
+                self.event_file_hovered(screen_id, &path, cursor_position);
+            }
+            WindowEvent::DroppedFile(path) => {
+                self.event_file_dropped(screen_id, &path, cursor_position);
+            }
+            WindowEvent::HoveredFileCancelled => {
+                self.event_file_hover_cancelled(screen_id);
             }
             WindowEvent::Ime(ime) => {
                 let data = ImeInputData::new(&ime);
-                self.event_ime(target.1, &data);
+                self.event_ime(screen_id, &data);
             }
             WindowEvent::MouseInput { state, button, .. } => {
-                self.event_mouse_button(target.1, &button, &state);
+                self.event_mouse_button(screen_id, &button, &state);
             }
-            WindowEvent::MouseWheel { delta, phase, .. } if phase == TouchPhase::Moved => {
-                match delta {
-                    MouseScrollDelta::LineDelta(x_delta, y_delta) => {
-                        self.event_wheel(target.1, x_delta, y_delta);
-                    }
-                    MouseScrollDelta::PixelDelta(_pos) => {
-                        // TODO: support touchpad devices
-                    }
+            WindowEvent::MouseWheel { delta, phase, .. } => {
+                let scroll = MouseScrollData::new(&delta, phase);
+                self.event_mouse_scroll(screen_id, &scroll);
+                // `event_wheel` is raised only by mouse wheels.
+                if let (MouseScrollDelta::LineDelta(x_delta, y_delta), TouchPhase::Moved) =
+                    (delta, phase)
+                {
+                    self.event_wheel(screen_id, x_delta, y_delta);
                 }
             }
+            WindowEvent::Touch(touch) => {
+                let data = TouchData::from(&touch);
+                self.event_touch(screen_id, &data);
+            }
             WindowEvent::CloseRequested => {
-                if self.close_screen(&target) {
+                if self.close_screen(screen_id) {
                     event_loop.exit();
                 }
             }
+            WindowEvent::ModifiersChanged(new_modifiers) => {
+                if let Some(target) = self.window_screen_mut(screen_id) {
+                    target.modifiers = new_modifiers.state();
+                }
+            }
             WindowEvent::KeyboardInput { event, .. } => {
                 use winit::keyboard::PhysicalKey;
+                let logical_key = logical_key_str(&event.logical_key);
+                let input = KeyInputData::new(&event, modifiers, &logical_key);
+                self.event_key_input(screen_id, &input);
+
                 if let PhysicalKey::Code(keycode) = &event.physical_key {
                     if let Ok(key) = keycode.try_into() {
                         let pressed = match event.state {
                             event::ElementState::Pressed => true,
                             event::ElementState::Released => false,
                         };
-                        self.event_keyboard(target.1, key, pressed);
+                        self.event_keyboard(screen_id, key, pressed);
                     }
                 }
 
@@ -234,30 +594,63 @@ impl ApplicationHandler<ProxyMessage> for Engine {
                     let text = text.as_ref();
                     if text.is_empty() == false {
                         text.chars().for_each(|c| {
-                            self.event_char_received(target.1, c);
+                            self.event_char_received(screen_id, c);
                         });
                     }
                 }
             }
+            WindowEvent::Focused(focused) => {
+                self.event_focused(screen_id, focused);
+            }
+            WindowEvent::ThemeChanged(theme) => {
+                self.event_theme_changed(screen_id, theme.into());
+            }
+            WindowEvent::Occluded(occluded) => {
+                if let Some(target) = self.window_screen_mut(screen_id) {
+                    target.visibility.set_occluded(occluded);
+                }
+                self.event_occluded(screen_id, occluded);
+            }
             WindowEvent::Resized(physical_size) => {
-                self.event_resized(target.1, physical_size.width, physical_size.height);
+                // Some platforms tell minimizing only by resizing to 0.
+                let minimized = window
+                    .is_minimized()
+                    .unwrap_or(physical_size.width == 0 || physical_size.height == 0);
+                if let Some(target) = self.window_screen_mut(screen_id) {
+                    target.visibility.set_minimized(minimized);
+                }
+                self.event_resized(screen_id, physical_size.width, physical_size.height);
             }
-            // WindowEvent::ScaleFactorChanged { new_inner_size, .. } => {
-            //     Engine::event_resized(target.1, new_inner_size.width, new_inner_size.height);
-            // }
             WindowEvent::ScaleFactorChanged {
                 scale_factor,
-                inner_size_writer,
+                mut inner_size_writer,
             } => {
-                _ = scale_factor;
-                _ = inner_size_writer;
-                // TODO: 分からん
-                // Engine::event_resized(target.1, new_inner_size.width, new_inner_size.height);
+                let old_scale_factor = match self.window_screen_mut(screen_id) {
+                    Some(target) => std::mem::replace(&mut target.scale_factor, scale_factor),
+                    None => scale_factor,
+                };
+                // The window is not resized yet. Suggest the size keeping its logical size.
+                let suggested_size: (u32, u32) = window
+                    .inner_size()
+                    .to_logical::<f64>(old_scale_factor)
+                    .to_physical::<u32>(scale_factor)
+                    .into();
+                let mut inner_size: SizeU32 = suggested_size.into();
+                self.event_scale_factor_changed(screen_id, scale_factor, &mut inner_size);
+                // If not changed by the handler, winit applies the size suggested by the platform.
+                let changed = (inner_size.width, inner_size.height) != suggested_size;
+                if changed && inner_size.width != 0 && inner_size.height != 0 {
+                    let new_size = PhysicalSize::new(inner_size.width, inner_size.height);
+                    _ = inner_size_writer.request_inner_size(new_size);
+                }
+                // The surface is not resized here. winit emits `WindowEvent::Resized` after this
+                // whenever the inner size differs from the current one, and the surface keeps
+                // its size otherwise.
             }
             WindowEvent::RedrawRequested => {
-                let continue_next = self.event_redraw_requested(target.1);
+                let continue_next = self.event_redraw_requested(screen_id);
                 if continue_next == false {
-                    if self.close_screen(&target) {
+                    if self.close_screen(screen_id) {
                         event_loop.exit();
                     }
                 }
@@ -267,25 +660,114 @@ impl ApplicationHandler<ProxyMessage> for Engine {
     }
 }
 
-static LOOP_PROXY: Mutex<Option<EventLoopProxy<ProxyMessage>>> = Mutex::new(None);
+static LOOP_PROXY: Mutex<Option<LoopProxy>> = Mutex::new(None);
+
+/// Sender of messages to the main loop,
+/// which is the event loop of winit or the headless loop for offscreen screens.
+#[derive(Clone)]
+pub(crate) enum LoopProxy {
+    EventLoop(EventLoopProxy<ProxyMessage>),
+    Headless(mpsc::Sender<ProxyMessage>),
+}
+
+impl LoopProxy {
+    pub fn send_event(&self, message: ProxyMessage) -> Result<(), Box<dyn Error>> {
+        let result = match self {
+            Self::EventLoop(proxy) => proxy.send_event(message).map_err(|err| err.0),
+            Self::Headless(sender) => sender.send(message).map_err(|err| err.0),
+        };
+        result.map_err(|message| {
+            message.discard();
+            EngineErr::NOT_RUNNING.into()
+        })
+    }
+}
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug)]
 pub(crate) enum ProxyMessage {
-    CreateScreen(ScreenConfig),
+    CreateScreen(Box<OwnedScreenConfig>),
+    SetCustomCursor(Arc<window::Window>, CustomCursor),
+    RunOnMainThread(MainThreadTask),
+    /// close the screen of the serial number (See [`Screen::serial`])
+    CloseScreen(u64),
+    Exit,
+}
+
+impl ProxyMessage {
+    /// Drop the message which is not sent.
+    /// The failure is reported to the sender by the return value, not by the callbacks of the message.
+    fn discard(self) {
+        if let Self::RunOnMainThread(mut task) = self {
+            task.completed = None;
+        }
+    }
+}
+
+/// Callback invoked on the main thread (the thread running the event loop).
+#[derive(Debug)]
+pub(crate) struct MainThreadTask {
+    callback: MainThreadCallbackFn,
+    token: usize,
+    completed: Option<MainThreadCompletedFn>,
 }
 
-pub(crate) fn get_loop_proxy() -> Result<EventLoopProxy<ProxyMessage>, EngineErr> {
+impl MainThreadTask {
+    pub fn new(
+        callback: MainThreadCallbackFn,
+        token: usize,
+        completed: Option<MainThreadCompletedFn>,
+    ) -> Self {
+        Self {
+            callback,
+            token,
+            completed,
+        }
+    }
+
+    fn run(mut self) {
+        let f = self.callback;
+        f(self.token);
+        if let Some(completed) = self.completed.take() {
+            completed(self.token, ApiResult::ok());
+        }
+    }
+}
+
+impl Drop for MainThreadTask {
+    fn drop(&mut self) {
+        // The task is dropped without running. (e.g. the engine exits before handling it)
+        if let Some(completed) = self.completed.take() {
+            set_tls_last_error(EngineErr::NOT_RUNNING);
+            completed(self.token, ApiResult::err());
+        }
+    }
+}
+
+pub(crate) fn get_loop_proxy() -> Result<LoopProxy, EngineErr> {
     let proxy = LOOP_PROXY.lock().unwrap();
     proxy.clone().ok_or(EngineErr::NOT_RUNNING)
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-struct ScreenIdData(window::WindowId, ScreenId);
+struct WindowScreenData {
+    window: Arc<window::Window>,
+    screen_id: ScreenId,
+    scale_factor: f64,
+    modifiers: ModifiersState,
+    cursor_position: (f32, f32),
+    visibility: Arc<WindowVisibility>,
+    throttle_when_hidden: bool,
+    screen_serial: u64,
+}
 
 pub(crate) fn send_proxy_message(message: ProxyMessage) -> Result<(), Box<dyn Error>> {
-    let proxy = get_loop_proxy()?;
-    proxy.send_event(message)?;
-    Ok(())
+    let proxy = match get_loop_proxy() {
+        Ok(proxy) => proxy,
+        Err(err) => {
+            message.discard();
+            return Err(err.into());
+        }
+    };
+    proxy.send_event(message)
 }
 
 pub(crate) static DEBUG_PRINTLN: RwLock<Option<DebugPrintlnFn>> = RwLock::new(None);
@@ -309,6 +791,7 @@ pub(crate) fn engine_start(
     engine_config: &EngineCoreConfig,
     screen_config: &ScreenConfig,
 ) -> Result<(), Box<dyn Error>> {
+    let screen_config = Box::new(OwnedScreenConfig::new(screen_config)?);
     if IS_ENGINE_RUNNING.swap(true, Ordering::Relaxed) {
         return Err(EngineErr::ALREADY_RUNNING.into());
     }
@@ -316,21 +799,39 @@ pub(crate) fn engine_start(
         .write()
         .unwrap()
         .replace(engine_config.debug_println);
-    let mut event_loop = EventLoop::with_user_event().build()?;
-    event_loop.set_control_flow(ControlFlow::Poll);
+    if screen_config.offscreen.to_ref_option().is_some() {
+        // Offscreen screens need no window system. (e.g. CI on Linux without a display server)
+        let (sender, receiver) = mpsc::channel();
+
+        debug_println!("[corehikari] engine start (headless)");
+        let mut engine = Engine::new(engine_config);
+        {
+            let mut proxy = LOOP_PROXY.lock().unwrap();
+            *proxy = Some(LoopProxy::Headless(sender));
+        }
+        env_logger::init();
+
+        get_loop_proxy()
+            .unwrap()
+            .send_event(ProxyMessage::CreateScreen(screen_config))?;
+        engine.run_headless(receiver);
+    } else {
+        let mut event_loop = EventLoop::with_user_event().build()?;
+        event_loop.set_control_flow(ControlFlow::Poll);
+
+        debug_println!("[corehikari] engine start");
+        let mut engine = Engine::new(engine_config);
+        {
+            let mut proxy = LOOP_PROXY.lock().unwrap();
+            *proxy = Some(LoopProxy::EventLoop(event_loop.create_proxy()));
+        }
+        env_logger::init();
 
-    debug_println!("[corehikari] engine start");
-    let mut engine = Engine::new(engine_config);
-    {
-        let mut proxy = LOOP_PROXY.lock().unwrap();
-        *proxy = Some(event_loop.create_proxy());
+        get_loop_proxy()
+            .unwrap()
+            .send_event(ProxyMessage::CreateScreen(screen_config))?;
+        event_loop.run_app_on_demand(&mut engine)?;
     }
-    env_logger::init();
-
-    get_loop_proxy()
-        .unwrap()
-        .send_event(ProxyMessage::CreateScreen(*screen_config))?;
-    event_loop.run_app_on_demand(&mut engine)?;
     IS_ENGINE_RUNNING.store(false, Ordering::Relaxed);
 
     debug_println!("[corehikari] engine stop");
@@ -378,6 +879,8 @@ impl EngineErr {
 
     const NOT_RUNNING: Self = Self::new("The engine is not running");
     const ALREADY_RUNNING: Self = Self::new("The engine is already running");
+    const NO_DISPLAY: Self = Self::new("The engine is running without the window system");
+    pub const NO_WINDOW: Self = Self::new("The screen has no window");
 }
 
 #[repr(transparent)]
@@ -27,10 +27,172 @@ static_assertions::assert_impl_all!(ScreenConfig: Send, Sync);
 /// - called from multiple threads simultaneously with same args
 #[no_mangle]
 extern "cdecl" fn hikari_create_screen(config: &ScreenConfig) -> ApiResult {
-    let result = send_proxy_message(ProxyMessage::CreateScreen(*config));
+    let f = || -> Result<_, Box<dyn Error>> {
+        let config = Box::new(OwnedScreenConfig::new(config)?);
+        send_proxy_message(ProxyMessage::CreateScreen(config))
+    };
+    ApiResult::ok_or_set_error(f())
+}
+
+/// Close the screen in the event loop. `event_closing` is raised as well as the close button of the window.
+/// The engine exits according to `ExitPolicy`.
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_close(screen: &Screen) -> ApiResult {
+    let result = send_proxy_message(ProxyMessage::CloseScreen(screen.serial()));
+    ApiResult::ok_or_set_error(result)
+}
+
+/// Exit the event loop. All remaining screens are closed without `event_closing`.
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_engine_exit() -> ApiResult {
+    let result = send_proxy_message(ProxyMessage::Exit);
     ApiResult::ok_or_set_error(result)
 }
 
+/// Invoke `callback` with `token` on the main thread (the thread running the event loop).
+/// `completed` is called only if this returns ok. Then it is invoked exactly once:
+/// on the main thread after `callback` returns, or with an error if `callback` is never invoked
+/// (e.g. the engine exits before running it).
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_post_to_main_thread(
+    callback: MainThreadCallbackFn,
+    token: usize,
+    completed: Option<MainThreadCompletedFn>,
+) -> ApiResult {
+    let task = MainThreadTask::new(callback, token, completed);
+    let result = send_proxy_message(ProxyMessage::RunOnMainThread(task));
+    ApiResult::ok_or_set_error(result)
+}
+
+/// Recreate the device, the queue and the surface of the screen. (e.g. after `event_device_lost`)
+/// All GPU objects created from the old device must be recreated. `info` receives the new info of the screen.
+/// If it fails, the screen has no surface to render to until it succeeds by calling this again.
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_recreate_device(
+    screen: &Screen,
+    info: &mut ScreenInfo,
+) -> ApiResult {
+    let result = screen.recreate_device().map(|_| {
+        *info = screen.get_info();
+    });
+    ApiResult::ok_or_set_error(result)
+}
+
+/// Get the capabilities of the surface of the screen.
+/// `callback` is invoked in this call. (The slices are valid only in the callback)
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_get_surface_capabilities(
+    screen: &Screen,
+    token: usize,
+    callback: extern "cdecl" fn(token: usize, capabilities: &SurfaceCapabilities),
+) -> ApiResult {
+    let caps = screen.surface_capabilities();
+    let formats: Vec<TextureFormat> = caps
+        .formats
+        .iter()
+        .filter_map(|&format| format.try_into().ok())
+        .collect();
+    let present_modes: Vec<PresentMode> = caps.present_modes.iter().map(|&x| x.into()).collect();
+    let alpha_modes: Vec<CompositeAlphaMode> = caps.alpha_modes.iter().map(|&x| x.into()).collect();
+    let capabilities = SurfaceCapabilities {
+        formats: Slice::new(&formats),
+        present_modes: Slice::new(&present_modes),
+        alpha_modes: Slice::new(&alpha_modes),
+    };
+    callback(token, &capabilities);
+    ApiResult::ok()
+}
+
+/// Reconfigure the surface with the present mode. (e.g. switching VSync)
+/// It fails if the surface does not support the mode (See `hikari_screen_get_surface_capabilities`),
+/// or if the screen is offscreen.
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_present_mode(
+    screen: &Screen,
+    present_mode: PresentMode,
+) -> ApiResult {
+    let result = screen.set_present_mode(present_mode.to_wgpu_type());
+    ApiResult::ok_or_set_error(result)
+}
+
+/// Get the features enabled on the device of the screen.
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_get_features(screen: &Screen) -> ApiValueResult<Features> {
+    ApiValueResult::ok(screen.device().features().into())
+}
+
+/// Get the limits of the device of the screen.
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_get_limits(screen: &Screen) -> ApiValueResult<wgpu::Limits> {
+    ApiValueResult::ok(screen.device().limits())
+}
+
+/// Enumerate the adapters of the backends. `callback` is invoked with each adapter in this call.
+/// Returns the number of adapters. (The index can be used as `adapter_index` of `ScreenConfig`)
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_enumerate_adapters(
+    backends: wgpu::Backends,
+    token: usize,
+    callback: extern "cdecl" fn(token: usize, index: usize, info: &AdapterInfo),
+) -> ApiValueResult<usize> {
+    let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor {
+        backends,
+        flags: wgpu::InstanceFlags::empty(),
+        backend_options: wgpu::BackendOptions::default(),
+    });
+    let adapters = instance.enumerate_adapters(backends);
+    for (index, adapter) in adapters.iter().enumerate() {
+        let info = adapter.get_info();
+        callback(token, index, &AdapterInfo::from(&info));
+    }
+    ApiValueResult::ok(adapters.len())
+}
+
 static_assertions::assert_impl_all!(Screen: Send, Sync);
 static_assertions::assert_impl_all!(Slice<u8>: Send, Sync);
 
@@ -55,7 +217,7 @@ extern "cdecl" fn hikari_screen_resize_surface(
 /// - called from multiple threads simultaneously with same args
 #[no_mangle]
 extern "cdecl" fn hikari_screen_request_redraw(screen: &Screen) -> ApiResult {
-    screen.window.request_redraw();
+    screen.request_redraw();
     ApiResult::ok()
 }
 
@@ -64,7 +226,7 @@ extern "cdecl" fn hikari_create_command_encoder(
     screen: &Screen,
 ) -> ApiBoxResult<wgpu::CommandEncoder> {
     let encoder = screen
-        .device
+        .device()
         .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
     ApiBoxResult::ok(Box::new(encoder))
 }
@@ -74,47 +236,36 @@ extern "cdecl" fn hikari_finish_command_encoder(
     screen: &Screen,
     encoder: Box<wgpu::CommandEncoder>,
 ) {
-    screen.queue.submit(std::iter::once(encoder.finish()));
+    screen.queue().submit(std::iter::once(encoder.finish()));
 }
 
 #[no_mangle]
 extern "cdecl" fn hikari_get_surface_texture(
     screen: &Screen,
-) -> ApiValueResult<Option<Box<wgpu::SurfaceTexture>>> {
-    match screen.surface.get_current_texture() {
-        Ok(surface_texture) => {
-            let value = Some(Box::new(surface_texture));
-            ApiValueResult::ok(value)
-        }
-        Err(wgpu::SurfaceError::Lost) => {
-            let size = screen.window.inner_size();
-            screen.resize_surface(size.width, size.height);
-            ApiValueResult::ok(None)
-        }
-        Err(err) => {
-            set_tls_last_error(err);
-            ApiValueResult::err()
-        }
-    }
+) -> ApiValueResult<Option<Box<SurfaceTexture>>> {
+    let result = screen
+        .get_current_texture()
+        .map(|surface_texture| surface_texture.map(Box::new));
+    ApiValueResult::ok_or_set_error(result)
 }
 
-static_assertions::assert_impl_all!(Box<wgpu::SurfaceTexture>: Send, Sync);
-static_assertions::assert_impl_all!(wgpu::SurfaceTexture: Send, Sync);
+static_assertions::assert_impl_all!(Box<SurfaceTexture>: Send, Sync);
+static_assertions::assert_impl_all!(SurfaceTexture: Send, Sync);
 
 #[no_mangle]
-extern "cdecl" fn hikari_destroy_surface_texture(surface_texture: Box<wgpu::SurfaceTexture>) {
+extern "cdecl" fn hikari_destroy_surface_texture(surface_texture: Box<SurfaceTexture>) {
     drop(surface_texture);
 }
 
 #[no_mangle]
 extern "cdecl" fn hikari_surface_texture_to_texture(
-    surface_texture: &wgpu::SurfaceTexture,
+    surface_texture: &SurfaceTexture,
 ) -> &wgpu::Texture {
-    &surface_texture.texture
+    surface_texture.texture()
 }
 
 #[no_mangle]
-extern "cdecl" fn hikari_present_surface_texture(surface_texture: Box<wgpu::SurfaceTexture>) {
+extern "cdecl" fn hikari_present_surface_texture(surface_texture: Box<SurfaceTexture>) {
     surface_texture.present()
 }
 
@@ -124,10 +275,122 @@ extern "cdecl" fn hikari_present_surface_texture(surface_texture: Box<wgpu::Surf
 /// - called from multiple threads simultaneously with same args
 #[no_mangle]
 extern "cdecl" fn hikari_screen_set_title(screen: &Screen, title: Slice<u8>) -> ApiResult {
-    let result = title.as_str().map(|title| {
-        screen.window.set_title(title);
-    });
-    ApiResult::ok_or_set_error(result)
+    let f = || -> Result<_, Box<dyn Error>> {
+        let title = title.as_str()?;
+        // Offscreen screens have no title bar. Just ignore it.
+        if let Ok(window) = screen.window() {
+            window.set_title(title);
+        }
+        Ok(())
+    };
+    ApiResult::ok_or_set_error(f())
+}
+
+/// Show or hide the window. (e.g. show it after the first frame is rendered)
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_visible(screen: &Screen, visible: bool) -> ApiResult {
+    ApiResult::ok_or_set_error(screen.set_visible(visible))
+}
+
+/// Get the current theme of the window. Returns none if the platform cannot tell it.
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_get_theme(screen: &Screen) -> ApiValueResult<Opt<Theme>> {
+    let result = screen
+        .window()
+        .map(|window| window.theme().map(Theme::from).into());
+    ApiValueResult::ok_or_set_error(result)
+}
+
+/// Set the theme of the window. `System` follows the system theme.
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_theme(screen: &Screen, theme: Theme) -> ApiResult {
+    let f = || -> Result<_, Box<dyn Error>> {
+        screen.window()?.set_theme(theme.to_winit_type());
+        Ok(())
+    };
+    ApiResult::ok_or_set_error(f())
+}
+
+/// Get the UTF-8 text of the clipboard, which is written to `buf` up to `buflen` bytes.
+/// Returns the byte length of the whole text.
+/// Call again with a larger buffer if it is larger than `buflen`.
+///
+/// # Thread Safety
+/// Only from main thread.
+/// (The clipboard is kept on the main thread. `NSPasteboard` is not thread safe on macOS.)
+/// ## NG
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_clipboard_get_text(
+    screen: &Screen,
+    buf: *mut u8,
+    buflen: usize,
+) -> ApiValueResult<usize> {
+    let f = || -> Result<_, Box<dyn Error>> {
+        let text = screen.clipboard_text()?;
+        let bytes = text.as_bytes();
+        let len = bytes.len().min(buflen);
+        if len > 0 {
+            let buf = unsafe { std::slice::from_raw_parts_mut(buf, len) };
+            buf.copy_from_slice(&bytes[..len]);
+        }
+        Ok(bytes.len())
+    };
+    ApiValueResult::ok_or_set_error(f())
+}
+
+/// Set the UTF-8 text to the clipboard.
+///
+/// # Thread Safety
+/// Only from main thread.
+/// (The clipboard is kept on the main thread. `NSPasteboard` is not thread safe on macOS.)
+/// ## NG
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_clipboard_set_text(screen: &Screen, text: Slice<u8>) -> ApiResult {
+    let f = || -> Result<_, Box<dyn Error>> {
+        let text = text.as_str()?;
+        screen.set_clipboard_text(text)
+    };
+    ApiResult::ok_or_set_error(f())
+}
+
+/// Set the window icon from RGBA pixels. (`rgba.len()` must be `width * height * 4`)
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_icon(
+    screen: &Screen,
+    rgba: Slice<u8>,
+    width: u32,
+    height: u32,
+) -> ApiResult {
+    let icon = IconImage {
+        rgba,
+        width,
+        height,
+    };
+    ApiResult::ok_or_set_error(screen.set_icon(&icon))
 }
 
 /// # Thread Safety
@@ -222,7 +485,7 @@ extern "cdecl" fn hikari_screen_set_inner_size(
 /// - called from multiple threads simultaneously with same args
 #[no_mangle]
 extern "cdecl" fn hikari_screen_get_inner_size(screen: &Screen) -> ApiValueResult<SizeU32> {
-    let size: (u32, u32) = screen.window.inner_size().into();
+    let size: (u32, u32) = screen.inner_size().into();
     ApiValueResult::ok(size.into())
 }
 
@@ -232,7 +495,10 @@ extern "cdecl" fn hikari_screen_get_inner_size(screen: &Screen) -> ApiValueResul
 /// - called from multiple threads simultaneously with same args
 #[no_mangle]
 extern "cdecl" fn hikari_screen_get_scale_factor(screen: &Screen) -> ApiValueResult<f64> {
-    let scale_factor = screen.window.scale_factor();
+    let scale_factor = match screen.window() {
+        Ok(window) => window.scale_factor(),
+        Err(_) => 1.0,
+    };
     ApiValueResult::ok(scale_factor)
 }
 
@@ -244,7 +510,7 @@ extern "cdecl" fn hikari_screen_set_location(
     monitor_id: Opt<MonitorId>,
 ) -> ApiResult {
     let f = || -> Result<_, Box<dyn Error>> {
-        let window = &screen.window;
+        let window = screen.window()?;
         let monitor = match monitor_id.to_option() {
             Some(id) => id.monitor(),
             None => window.current_monitor().ok_or("no monitors")?,
@@ -269,7 +535,7 @@ extern "cdecl" fn hikari_screen_get_location(
     monitor_id: Opt<MonitorId>,
 ) -> ApiValueResult<Tuple<i32, i32>> {
     let f = || -> Result<_, Box<dyn Error>> {
-        let window = &screen.window;
+        let window = screen.window()?;
         let monitor = match monitor_id.to_option() {
             Some(id) => id.monitor(),
             None => window.current_monitor().ok_or("no monitors")?,
@@ -281,6 +547,116 @@ extern "cdecl" fn hikari_screen_get_location(
     ApiValueResult::ok_or_set_error(f())
 }
 
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_cursor_grab(
+    screen: &Screen,
+    mode: CursorGrabMode,
+) -> ApiResult {
+    let f = || -> Result<_, Box<dyn Error>> {
+        screen.window()?.set_cursor_grab(mode.to_winit_type())?;
+        Ok(())
+    };
+    ApiResult::ok_or_set_error(f())
+}
+
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_cursor_visible(screen: &Screen, visible: bool) -> ApiResult {
+    let f = || -> Result<_, Box<dyn Error>> {
+        screen.window()?.set_cursor_visible(visible);
+        Ok(())
+    };
+    ApiResult::ok_or_set_error(f())
+}
+
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_cursor_icon(screen: &Screen, icon: CursorIcon) -> ApiResult {
+    let f = || -> Result<_, Box<dyn Error>> {
+        screen.window()?.set_cursor(icon.to_winit_type());
+        Ok(())
+    };
+    ApiResult::ok_or_set_error(f())
+}
+
+/// Create a cursor from RGBA pixels. (`rgba.len()` must be `width * height * 4`)
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_create_custom_cursor(
+    rgba: Slice<u8>,
+    width: u16,
+    height: u16,
+    hotspot_x: u16,
+    hotspot_y: u16,
+) -> ApiBoxResult<CustomCursor> {
+    let result = CustomCursor::from_rgba(&rgba, width, height, hotspot_x, hotspot_y).map(Box::new);
+    ApiBoxResult::ok_or_set_error(result)
+}
+
+static_assertions::assert_impl_all!(Box<CustomCursor>: Send, Sync);
+static_assertions::assert_impl_all!(CustomCursor: Send, Sync);
+
+/// Destroy [`Box<CustomCursor>`].
+/// Screens using the cursor keep it.
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// ## NG
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_destroy_custom_cursor(cursor: Box<CustomCursor>) {
+    drop(cursor)
+}
+
+/// The cursor is applied asynchronously in the event loop.
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_custom_cursor(
+    screen: &Screen,
+    cursor: &CustomCursor,
+) -> ApiResult {
+    let f = || -> Result<_, Box<dyn Error>> {
+        let window = screen.window_arc()?;
+        send_proxy_message(ProxyMessage::SetCustomCursor(window, cursor.clone()))
+    };
+    ApiResult::ok_or_set_error(f())
+}
+
+/// Move the cursor to the physical position in the screen.
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_cursor_position(screen: &Screen, x: f32, y: f32) -> ApiResult {
+    let f = || -> Result<_, Box<dyn Error>> {
+        let pos = winit::dpi::PhysicalPosition::new(x, y);
+        screen.window()?.set_cursor_position(pos)?;
+        Ok(())
+    };
+    ApiResult::ok_or_set_error(f())
+}
+
 /// # Thread Safety
 /// (iOS) Only from main thread.
 /// (`Window::current_monitor` can only be called on the main thread in iOS.)
@@ -290,8 +666,9 @@ extern "cdecl" fn hikari_screen_get_location(
 #[no_mangle]
 extern "cdecl" fn hikari_current_monitor(screen: &Screen) -> ApiValueResult<Opt<MonitorId>> {
     let result = screen
-        .window
-        .current_monitor()
+        .window()
+        .ok()
+        .and_then(|window| window.current_monitor())
         .map(|monitor| Some(MonitorId::new(monitor)).into())
         .ok_or("no monitors");
     ApiValueResult::ok_or_set_error(result)
@@ -305,7 +682,10 @@ extern "cdecl" fn hikari_current_monitor(screen: &Screen) -> ApiValueResult<Opt<
 /// - called from multiple threads simultaneously with same args
 #[no_mangle]
 extern "cdecl" fn hikari_monitor_count(screen: &Screen) -> ApiValueResult<usize> {
-    let count = screen.window.available_monitors().count();
+    let count = match screen.window() {
+        Ok(window) => window.available_monitors().count(),
+        Err(_) => 0,
+    };
     ApiValueResult::ok(count)
 }
 
@@ -323,13 +703,260 @@ extern "cdecl" fn hikari_monitors(
 ) -> ApiValueResult<usize> {
     let mut i: usize = 0;
     let buf = unsafe { std::slice::from_raw_parts_mut(buf, buflen) };
-    for monitor in screen.window.available_monitors().take(buflen) {
+    let window = match screen.window() {
+        Ok(window) => window,
+        Err(_) => return ApiValueResult::ok(0),
+    };
+    for monitor in window.available_monitors().take(buflen) {
         buf[i] = MonitorId::new(monitor);
         i += 1;
     }
     ApiValueResult::ok(i)
 }
 
+/// # Thread Safety
+/// (iOS) Only from main thread.
+/// (`Window::primary_monitor` can only be called on the main thread in iOS.)
+/// ## NG
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_primary_monitor(screen: &Screen) -> ApiValueResult<Opt<MonitorId>> {
+    // Some platforms (e.g. Wayland) have no concept of the primary monitor.
+    let monitor: Opt<MonitorId> = screen
+        .window()
+        .ok()
+        .and_then(|window| window.primary_monitor())
+        .map(MonitorId::new)
+        .into();
+    ApiValueResult::ok(monitor)
+}
+
+/// Get the information of the monitor.
+/// The UTF-8 name is written to `name_buf` up to `name_buflen` bytes.
+/// Call again with a larger buffer if `name_len` of the result is larger than `name_buflen`.
+///
+/// # Thread Safety
+/// (iOS) Only from main thread.
+/// (`winit::monitor::MonitorHandle` can only be used on the main thread in iOS.)
+/// ## NG
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_monitor_get_info(
+    monitor_id: MonitorId,
+    name_buf: *mut u8,
+    name_buflen: usize,
+) -> ApiValueResult<MonitorInfo> {
+    let monitor = monitor_id.monitor();
+    if let Some(name) = monitor.name() {
+        let bytes = name.as_bytes();
+        let len = bytes.len().min(name_buflen);
+        if len > 0 {
+            let buf = unsafe { std::slice::from_raw_parts_mut(name_buf, len) };
+            buf.copy_from_slice(&bytes[..len]);
+        }
+    }
+    ApiValueResult::ok(MonitorInfo::from(&monitor))
+}
+
+/// # Thread Safety
+/// (iOS) Only from main thread.
+/// (`winit::monitor::MonitorHandle` can only be used on the main thread in iOS.)
+/// ## NG
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_monitor_video_mode_count(monitor_id: MonitorId) -> ApiValueResult<usize> {
+    let count = monitor_id.monitor().video_modes().count();
+    ApiValueResult::ok(count)
+}
+
+/// # Thread Safety
+/// (iOS) Only from main thread.
+/// (`winit::monitor::MonitorHandle` can only be used on the main thread in iOS.)
+/// ## NG
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_monitor_video_modes(
+    monitor_id: MonitorId,
+    buf: *mut VideoMode,
+    buflen: usize,
+) -> ApiValueResult<usize> {
+    let mut i: usize = 0;
+    let buf = unsafe { std::slice::from_raw_parts_mut(buf, buflen) };
+    for video_mode in monitor_id.monitor().video_modes().take(buflen) {
+        buf[i] = VideoMode::from(&video_mode);
+        i += 1;
+    }
+    ApiValueResult::ok(i)
+}
+
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_style(screen: &Screen, style: WindowStyle) -> ApiResult {
+    let result = screen.set_style(style);
+    ApiResult::ok_or_set_error(result)
+}
+
+/// `video_mode` must be one of the video modes of the monitor. (See `hikari_monitor_video_modes`)
+///
+/// # Thread Safety
+/// (iOS) Only from main thread.
+/// (`winit::monitor::MonitorHandle` can only be used on the main thread in iOS.)
+/// ## NG
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_exclusive_fullscreen(
+    screen: &Screen,
+    monitor_id: MonitorId,
+    video_mode: &VideoMode,
+) -> ApiResult {
+    let result = screen.set_exclusive_fullscreen(&monitor_id.monitor(), video_mode);
+    ApiResult::ok_or_set_error(result)
+}
+
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_min_inner_size(
+    screen: &Screen,
+    size: Opt<SizeU32>,
+) -> ApiResult {
+    let result = screen.set_min_inner_size(size.to_option());
+    ApiResult::ok_or_set_error(result)
+}
+
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_max_inner_size(
+    screen: &Screen,
+    size: Opt<SizeU32>,
+) -> ApiResult {
+    let result = screen.set_max_inner_size(size.to_option());
+    ApiResult::ok_or_set_error(result)
+}
+
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_maximized(screen: &Screen, maximized: bool) -> ApiResult {
+    let f = || -> Result<_, Box<dyn Error>> {
+        screen.window()?.set_maximized(maximized);
+        Ok(())
+    };
+    ApiResult::ok_or_set_error(f())
+}
+
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_is_maximized(screen: &Screen) -> ApiValueResult<bool> {
+    let result = screen.window().map(|window| window.is_maximized());
+    ApiValueResult::ok_or_set_error(result)
+}
+
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_minimized(screen: &Screen, minimized: bool) -> ApiResult {
+    let f = || -> Result<_, Box<dyn Error>> {
+        screen.window()?.set_minimized(minimized);
+        Ok(())
+    };
+    ApiResult::ok_or_set_error(f())
+}
+
+/// Returns none if the platform cannot tell it. (e.g. Wayland)
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_is_minimized(screen: &Screen) -> ApiValueResult<Opt<bool>> {
+    let result = screen.window().map(|window| window.is_minimized().into());
+    ApiValueResult::ok_or_set_error(result)
+}
+
+/// Restore the window from the minimized or maximized state.
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_restore(screen: &Screen) -> ApiResult {
+    let f = || -> Result<_, Box<dyn Error>> {
+        let window = screen.window()?;
+        window.set_minimized(false);
+        window.set_maximized(false);
+        Ok(())
+    };
+    ApiResult::ok_or_set_error(f())
+}
+
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_always_on_top(
+    screen: &Screen,
+    always_on_top: bool,
+) -> ApiResult {
+    let result = screen.set_always_on_top(always_on_top);
+    ApiResult::ok_or_set_error(result)
+}
+
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_is_always_on_top(screen: &Screen) -> ApiValueResult<bool> {
+    ApiValueResult::ok_or_set_error(screen.is_always_on_top())
+}
+
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_set_decorations(screen: &Screen, decorations: bool) -> ApiResult {
+    let f = || -> Result<_, Box<dyn Error>> {
+        screen.window()?.set_decorations(decorations);
+        Ok(())
+    };
+    ApiResult::ok_or_set_error(f())
+}
+
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_screen_is_decorated(screen: &Screen) -> ApiValueResult<bool> {
+    let result = screen.window().map(|window| window.is_decorated());
+    ApiValueResult::ok_or_set_error(result)
+}
+
 /// # Thread Safety
 /// ## OK
 /// - called from any thread
@@ -342,7 +969,7 @@ extern "cdecl" fn hikari_write_texture(
     data_layout: &ImageDataLayout,
     size: &wgpu::Extent3d,
 ) -> ApiResult {
-    screen.queue.write_texture(
+    screen.queue().write_texture(
         texture.to_wgpu_type(),
         &data,
         data_layout.to_wgpu_type(),
@@ -361,7 +988,7 @@ extern "cdecl" fn hikari_create_bind_group_layout(
     desc: &BindGroupLayoutDescriptor,
 ) -> ApiBoxResult<wgpu::BindGroupLayout> {
     let value = desc.use_wgpu_type(|desc| {
-        let layout = screen.device.create_bind_group_layout(desc);
+        let layout = screen.device().create_bind_group_layout(desc);
         Box::new(layout)
     });
     ApiBoxResult::ok(value)
@@ -392,7 +1019,7 @@ extern "cdecl" fn hikari_create_bind_group(
     desc: &BindGroupDescriptor,
 ) -> ApiBoxResult<wgpu::BindGroup> {
     let value = desc.use_wgpu_type(|desc| {
-        let bind_group = screen.device.create_bind_group(desc);
+        let bind_group = screen.device().create_bind_group(desc);
         Box::new(bind_group)
     });
     ApiBoxResult::ok(value)
@@ -422,7 +1049,7 @@ extern "cdecl" fn hikari_create_pipeline_layout(
     screen: &Screen,
     desc: &PipelineLayoutDescriptor,
 ) -> ApiBoxResult<wgpu::PipelineLayout> {
-    let value = screen.device.create_pipeline_layout(&desc.to_wgpu_type());
+    let value = screen.device().create_pipeline_layout(&desc.to_wgpu_type());
     let value = Box::new(value);
     ApiBoxResult::ok(value)
 }
@@ -452,7 +1079,7 @@ extern "cdecl" fn hikari_create_render_pipeline(
     desc: &RenderPipelineDescriptor,
 ) -> ApiBoxResult<wgpu::RenderPipeline> {
     let result = desc.use_wgpu_type(|desc| {
-        let value = screen.device.create_render_pipeline(desc);
+        let value = screen.device().create_render_pipeline(desc);
         Ok(Box::new(value))
     });
     ApiBoxResult::ok_or_set_error(result)
@@ -484,7 +1111,7 @@ extern "cdecl" fn hikari_create_compute_pipeline(
     desc: &ComputePipelineDescriptor,
 ) -> ApiBoxResult<wgpu::ComputePipeline> {
     let result = desc.use_wgpu_type(|desc| {
-        let value = screen.device.create_compute_pipeline(desc);
+        let value = screen.device().create_compute_pipeline(desc);
         Ok(Box::new(value))
     });
     ApiBoxResult::ok_or_set_error(result)
@@ -513,7 +1140,7 @@ extern "cdecl" fn hikari_create_buffer(
     size: u64,
     usage: wgpu::BufferUsages,
 ) -> ApiBoxResult<wgpu::Buffer> {
-    let buffer = screen.device.create_buffer(&wgpu::BufferDescriptor {
+    let buffer = screen.device().create_buffer(&wgpu::BufferDescriptor {
         label: None,
         size,
         usage,
@@ -536,7 +1163,7 @@ extern "cdecl" fn hikari_create_buffer_init(
     use wgpu::util::DeviceExt;
 
     let buffer = screen
-        .device
+        .device()
         .create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: None,
             contents: &contents,
@@ -596,7 +1223,7 @@ extern "cdecl" fn hikari_copy_texture_to_buffer(
     }
 
     let mut encoder = screen
-        .device
+        .device()
         .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
     encoder.copy_texture_to_buffer(
         source.to_wgpu_type(),
@@ -606,7 +1233,7 @@ extern "cdecl" fn hikari_copy_texture_to_buffer(
         },
         *copy_size,
     );
-    screen.queue.submit(Some(encoder.finish()));
+    screen.queue().submit(Some(encoder.finish()));
     ApiResult::ok()
 }
 
@@ -618,8 +1245,8 @@ extern "cdecl" fn hikari_read_buffer(
     callback: extern "cdecl" fn(token: usize, result: ApiResult, view: *const u8, len: usize),
 ) -> ApiResult {
     wgpu::util::DownloadBuffer::read_buffer(
-        &screen.device,
-        &screen.queue,
+        &screen.device(),
+        &screen.queue(),
         &buffer_slice.to_wgpu_type(),
         move |result| match result {
             Ok(downloaded) => {
@@ -646,7 +1273,7 @@ extern "cdecl" fn hikari_create_sampler(
     screen: &Screen,
     desc: &SamplerDescriptor,
 ) -> ApiBoxResult<wgpu::Sampler> {
-    let sampler = screen.device.create_sampler(&desc.to_wgpu_type());
+    let sampler = screen.device().create_sampler(&desc.to_wgpu_type());
     let value = Box::new(sampler);
     ApiBoxResult::ok(value)
 }
@@ -677,7 +1304,7 @@ extern "cdecl" fn hikari_create_shader_module(
 ) -> ApiBoxResult<wgpu::ShaderModule> {
     let result = shader_source.as_str().map(|s| {
         let shader = screen
-            .device
+            .device()
             .create_shader_module(wgpu::ShaderModuleDescriptor {
                 label: None,
                 source: wgpu::ShaderSource::Wgsl(s.into()),
@@ -711,7 +1338,7 @@ extern "cdecl" fn hikari_create_texture(
     screen: &Screen,
     desc: &TextureDescriptor,
 ) -> ApiBoxResult<wgpu::Texture> {
-    let value = Box::new(screen.device.create_texture(&desc.to_wgpu_type()));
+    let value = Box::new(screen.device().create_texture(&desc.to_wgpu_type()));
     ApiBoxResult::ok(value)
 }
 
@@ -751,8 +1378,8 @@ extern "cdecl" fn hikari_create_texture_with_data(
 ) -> ApiBoxResult<wgpu::Texture> {
     use wgpu::util::DeviceExt;
 
-    let texture = screen.device.create_texture_with_data(
-        &screen.queue,
+    let texture = screen.device().create_texture_with_data(
+        &screen.queue(),
         &desc.to_wgpu_type(),
         Default::default(),
         &data,
@@ -869,7 +1496,7 @@ extern "cdecl" fn hikari_texture_format_guaranteed_format_features(
 ) -> ApiValueResult<TextureFormatFeatures> {
     let features: TextureFormatFeatures = format
         .to_wgpu_type()
-        .guaranteed_format_features(screen.device.features())
+        .guaranteed_format_features(screen.device().features())
         .into();
     ApiValueResult::ok(features)
 }
@@ -914,7 +1541,7 @@ extern "cdecl" fn hikari_write_buffer(
     offset: u64,
     data: Slice<u8>,
 ) -> ApiResult {
-    screen.queue.write_buffer(buffer, offset, &data);
+    screen.queue().write_buffer(buffer, offset, &data);
     ApiResult::ok()
 }
 
@@ -1095,16 +1722,53 @@ static_assertions::assert_impl_all!(winit::window::Window: Send, Sync);
 
 #[no_mangle]
 extern "cdecl" fn hikari_set_ime_allowed(screen: &Screen, allowed: bool) -> ApiResult {
-    screen.window.set_ime_allowed(allowed);
+    if let Ok(window) = screen.window() {
+        window.set_ime_allowed(allowed);
+    }
     ApiResult::ok()
 }
 
 #[no_mangle]
 extern "cdecl" fn hikari_set_ime_position(screen: &Screen, x: u32, y: u32) -> ApiResult {
-    let pos = winit::dpi::PhysicalPosition::new(x, y);
-    screen
-        .window
-        .set_ime_cursor_area(pos, PhysicalSize::new(30, 30));
+    if let Ok(window) = screen.window() {
+        let pos = winit::dpi::PhysicalPosition::new(x, y);
+        window.set_ime_cursor_area(pos, PhysicalSize::new(30, 30));
+    }
+    ApiResult::ok()
+}
+
+/// Set the area of the text cursor in physical pixels, which the IME candidate window avoids.
+///
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_set_ime_cursor_area(
+    screen: &Screen,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+) -> ApiResult {
+    // Offscreen screens have no IME. Just ignore it.
+    if let Ok(window) = screen.window() {
+        let pos = winit::dpi::PhysicalPosition::new(x, y);
+        window.set_ime_cursor_area(pos, PhysicalSize::new(width, height));
+    }
+    ApiResult::ok()
+}
+
+/// # Thread Safety
+/// ## OK
+/// - called from any thread
+/// - called from multiple threads simultaneously with same args
+#[no_mangle]
+extern "cdecl" fn hikari_set_ime_purpose(screen: &Screen, purpose: ImePurpose) -> ApiResult {
+    // Offscreen screens have no IME. Just ignore it.
+    if let Ok(window) = screen.window() {
+        window.set_ime_purpose(purpose.to_winit_type());
+    }
     ApiResult::ok()
 }
 
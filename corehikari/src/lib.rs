@@ -2,6 +2,7 @@ mod engine;
 mod ffi;
 mod screen;
 
+use crate::engine::ApiResult;
 use crate::screen::{Screen, ScreenId};
 use corehikari_macros::tagged_ref_union;
 use smallvec::SmallVec;
@@ -30,16 +31,126 @@ pub(crate) struct EngineCoreConfig {
     pub event_closing: ClosingEventFn,
     pub event_closed: ClosedEventFn,
     pub debug_println: DebugPrintlnFn,
+    pub event_mouse_scroll: MouseScrollEventFn,
+    pub event_scale_factor_changed: ScaleFactorChangedEventFn,
+    pub event_touch: TouchEventFn,
+    pub event_key_input: KeyInputEventFn,
+    pub event_raw_mouse_motion: RawMouseMotionEventFn,
+    pub event_file_hovered: FileHoveredEventFn,
+    pub event_file_dropped: FileDroppedEventFn,
+    pub event_file_hover_cancelled: FileHoverCancelledEventFn,
+    pub event_focused: FocusedEventFn,
+    pub event_occluded: OccludedEventFn,
+    pub exit_policy: ExitPolicy,
+    pub event_theme_changed: ThemeChangedEventFn,
+    pub event_device_lost: DeviceLostEventFn,
+}
+
+/// When the engine exits the event loop
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[allow(dead_code)] // because values are from FFI
+pub(crate) enum ExitPolicy {
+    /// exit when the last screen is closed
+    LastScreenClosed = 0,
+    /// exit when the first created screen is closed
+    MainScreenClosed = 1,
+    /// exit only by `hikari_engine_exit`
+    Explicit = 2,
 }
 
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
-pub(crate) struct ScreenConfig {
+#[derive(Debug)]
+pub(crate) struct ScreenConfig<'a> {
     pub style: WindowStyle,
     pub width: u32,
     pub height: u32,
     pub backend: wgpu::Backends,
     pub present_mode: PresentMode,
+    /// Create the screen without a window, which renders to an offscreen texture. (e.g. for automated tests)
+    /// If the first screen is offscreen, the engine runs without the window system.
+    pub offscreen: Opt<OffscreenConfig>,
+    /// Ignore redraw requests while the window is occluded or minimized,
+    /// and stop the event loop polling when all screens are hidden.
+    pub throttle_when_hidden: bool,
+    /// window icon (ignored for offscreen screens)
+    pub icon: Opt<IconImage<'a>>,
+    /// Make the background of the window transparent.
+    /// (use with `alpha_mode` other than `Opaque`)
+    pub transparent: bool,
+    /// preferred alpha mode of the surface (falls back to a supported mode)
+    pub alpha_mode: CompositeAlphaMode,
+    /// initial title of the window (UTF-8)
+    pub title: Slice<'a, u8>,
+    /// initial position of the window relative to `monitor` (in the desktop if no monitor is found)
+    /// (none means the center of `monitor`)
+    pub position: Opt<Tuple<i32, i32>>,
+    /// target monitor (none means the current monitor)
+    pub monitor: Opt<MonitorId>,
+    /// Create the window hidden. (show it by `hikari_screen_set_visible`)
+    pub hidden: bool,
+    pub power_preference: wgpu::PowerPreference,
+    /// index of the adapter in `hikari_enumerate_adapters` with the same backends
+    /// (none means the adapter is chosen by `power_preference` and `force_fallback_adapter`)
+    pub adapter_index: Opt<usize>,
+    /// Use the software adapter. (e.g. for testing)
+    pub force_fallback_adapter: bool,
+    /// Creating the screen fails if the adapter does not support them.
+    pub required_features: Features,
+    /// enabled only if the adapter supports them
+    pub optional_features: Features,
+    /// required limits (none means `wgpu::Limits::default()`)
+    pub limits: Opt<wgpu::Limits>,
+    /// Request the best limits of the adapter instead of `limits`.
+    pub use_adapter_limits: bool,
+    /// surface formats in order of preference (e.g. `Rgba16Float` for HDR)
+    /// The first sRGB format is used if none of them is supported. (ignored for offscreen screens)
+    pub preferred_surface_formats: Slice<'a, TextureFormat>,
+}
+
+/// ffi-safe [`wgpu::Features`]
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Features {
+    pub wgpu: wgpu::FeaturesWGPU,
+    pub webgpu: wgpu::FeaturesWebGPU,
+}
+
+impl Features {
+    pub fn to_wgpu_type(&self) -> wgpu::Features {
+        wgpu::Features::from_internal_flags(self.wgpu, self.webgpu)
+    }
+}
+
+impl From<wgpu::Features> for Features {
+    fn from(value: wgpu::Features) -> Self {
+        Self {
+            wgpu: value.features_wgpu,
+            webgpu: value.features_webgpu,
+        }
+    }
+}
+
+/// RGBA pixels of an icon. (`rgba.len()` must be `width * height * 4`)
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct IconImage<'a> {
+    pub rgba: Slice<'a, u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl IconImage<'_> {
+    pub fn to_winit_type(&self) -> Result<winit::window::Icon, winit::window::BadIcon> {
+        winit::window::Icon::from_rgba(self.rgba.to_vec(), self.width, self.height)
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct OffscreenConfig {
+    /// interval of redraw events in microseconds (0 means as fast as possible)
+    pub frame_interval_micros: u32,
 }
 
 #[repr(u32)]
@@ -67,6 +178,183 @@ impl PresentMode {
     }
 }
 
+impl From<wgpu::PresentMode> for PresentMode {
+    fn from(value: wgpu::PresentMode) -> Self {
+        match value {
+            wgpu::PresentMode::AutoVsync => Self::AutoVsync,
+            wgpu::PresentMode::AutoNoVsync => Self::AutoNoVsync,
+            wgpu::PresentMode::Fifo => Self::Fifo,
+            wgpu::PresentMode::FifoRelaxed => Self::FifoRelaxed,
+            wgpu::PresentMode::Immediate => Self::Immediate,
+            wgpu::PresentMode::Mailbox => Self::Mailbox,
+        }
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[allow(dead_code)] // because values are from FFI
+pub(crate) enum CompositeAlphaMode {
+    Auto = 0,
+    Opaque = 1,
+    PreMultiplied = 2,
+    PostMultiplied = 3,
+    Inherit = 4,
+}
+
+impl CompositeAlphaMode {
+    pub fn to_wgpu_type(&self) -> wgpu::CompositeAlphaMode {
+        match self {
+            Self::Auto => wgpu::CompositeAlphaMode::Auto,
+            Self::Opaque => wgpu::CompositeAlphaMode::Opaque,
+            Self::PreMultiplied => wgpu::CompositeAlphaMode::PreMultiplied,
+            Self::PostMultiplied => wgpu::CompositeAlphaMode::PostMultiplied,
+            Self::Inherit => wgpu::CompositeAlphaMode::Inherit,
+        }
+    }
+}
+
+impl From<wgpu::CompositeAlphaMode> for CompositeAlphaMode {
+    fn from(value: wgpu::CompositeAlphaMode) -> Self {
+        match value {
+            wgpu::CompositeAlphaMode::Auto => Self::Auto,
+            wgpu::CompositeAlphaMode::Opaque => Self::Opaque,
+            wgpu::CompositeAlphaMode::PreMultiplied => Self::PreMultiplied,
+            wgpu::CompositeAlphaMode::PostMultiplied => Self::PostMultiplied,
+            wgpu::CompositeAlphaMode::Inherit => Self::Inherit,
+        }
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[allow(dead_code)] // because values are from FFI
+pub(crate) enum Theme {
+    /// follow the system theme
+    System = 0,
+    Light = 1,
+    Dark = 2,
+}
+
+impl Theme {
+    pub fn to_winit_type(&self) -> Option<winit::window::Theme> {
+        match self {
+            Self::System => None,
+            Self::Light => Some(winit::window::Theme::Light),
+            Self::Dark => Some(winit::window::Theme::Dark),
+        }
+    }
+}
+
+impl From<winit::window::Theme> for Theme {
+    fn from(value: winit::window::Theme) -> Self {
+        match value {
+            winit::window::Theme::Light => Self::Light,
+            winit::window::Theme::Dark => Self::Dark,
+        }
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[allow(dead_code)] // because values are from FFI
+pub(crate) enum CursorGrabMode {
+    None = 0,
+    Confined = 1,
+    Locked = 2,
+}
+
+impl CursorGrabMode {
+    pub fn to_winit_type(&self) -> window::CursorGrabMode {
+        match self {
+            Self::None => window::CursorGrabMode::None,
+            Self::Confined => window::CursorGrabMode::Confined,
+            Self::Locked => window::CursorGrabMode::Locked,
+        }
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[allow(dead_code)] // because values are from FFI
+pub(crate) enum CursorIcon {
+    Default = 0,
+    ContextMenu = 1,
+    Help = 2,
+    Pointer = 3,
+    Progress = 4,
+    Wait = 5,
+    Cell = 6,
+    Crosshair = 7,
+    Text = 8,
+    VerticalText = 9,
+    Alias = 10,
+    Copy = 11,
+    Move = 12,
+    NoDrop = 13,
+    NotAllowed = 14,
+    Grab = 15,
+    Grabbing = 16,
+    EResize = 17,
+    NResize = 18,
+    NeResize = 19,
+    NwResize = 20,
+    SResize = 21,
+    SeResize = 22,
+    SwResize = 23,
+    WResize = 24,
+    EwResize = 25,
+    NsResize = 26,
+    NeswResize = 27,
+    NwseResize = 28,
+    ColResize = 29,
+    RowResize = 30,
+    AllScroll = 31,
+    ZoomIn = 32,
+    ZoomOut = 33,
+}
+
+impl CursorIcon {
+    pub fn to_winit_type(&self) -> window::CursorIcon {
+        match self {
+            Self::Default => window::CursorIcon::Default,
+            Self::ContextMenu => window::CursorIcon::ContextMenu,
+            Self::Help => window::CursorIcon::Help,
+            Self::Pointer => window::CursorIcon::Pointer,
+            Self::Progress => window::CursorIcon::Progress,
+            Self::Wait => window::CursorIcon::Wait,
+            Self::Cell => window::CursorIcon::Cell,
+            Self::Crosshair => window::CursorIcon::Crosshair,
+            Self::Text => window::CursorIcon::Text,
+            Self::VerticalText => window::CursorIcon::VerticalText,
+            Self::Alias => window::CursorIcon::Alias,
+            Self::Copy => window::CursorIcon::Copy,
+            Self::Move => window::CursorIcon::Move,
+            Self::NoDrop => window::CursorIcon::NoDrop,
+            Self::NotAllowed => window::CursorIcon::NotAllowed,
+            Self::Grab => window::CursorIcon::Grab,
+            Self::Grabbing => window::CursorIcon::Grabbing,
+            Self::EResize => window::CursorIcon::EResize,
+            Self::NResize => window::CursorIcon::NResize,
+            Self::NeResize => window::CursorIcon::NeResize,
+            Self::NwResize => window::CursorIcon::NwResize,
+            Self::SResize => window::CursorIcon::SResize,
+            Self::SeResize => window::CursorIcon::SeResize,
+            Self::SwResize => window::CursorIcon::SwResize,
+            Self::WResize => window::CursorIcon::WResize,
+            Self::EwResize => window::CursorIcon::EwResize,
+            Self::NsResize => window::CursorIcon::NsResize,
+            Self::NeswResize => window::CursorIcon::NeswResize,
+            Self::NwseResize => window::CursorIcon::NwseResize,
+            Self::ColResize => window::CursorIcon::ColResize,
+            Self::RowResize => window::CursorIcon::RowResize,
+            Self::AllScroll => window::CursorIcon::AllScroll,
+            Self::ZoomIn => window::CursorIcon::ZoomIn,
+            Self::ZoomOut => window::CursorIcon::ZoomOut,
+        }
+    }
+}
+
 #[repr(u32)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 #[allow(dead_code)] // because values are from FFI
@@ -1434,6 +1722,136 @@ mod tests {
         assert_eq!(A::EacRg11Snorm, B::EacRg11Snorm.try_into().unwrap());
 
     }
+
+    #[test]
+    fn test_modifiers() {
+        use crate::Modifiers;
+        use winit::keyboard::ModifiersState;
+
+        assert_eq!(
+            Modifiers::from(ModifiersState::empty()),
+            Modifiers::default()
+        );
+        assert_eq!(Modifiers::from(ModifiersState::SHIFT), Modifiers::SHIFT);
+        assert_eq!(
+            Modifiers::from(ModifiersState::CONTROL | ModifiersState::SHIFT),
+            Modifiers::CONTROL | Modifiers::SHIFT
+        );
+        assert_eq!(
+            Modifiers::from(ModifiersState::all()),
+            Modifiers::SHIFT | Modifiers::CONTROL | Modifiers::ALT | Modifiers::SUPER
+        );
+    }
+
+    #[test]
+    fn test_logical_key_str() {
+        use crate::logical_key_str;
+        use winit::keyboard::{Key, NamedKey};
+
+        assert_eq!(logical_key_str(&Key::Named(NamedKey::Enter)), "Enter");
+        assert_eq!(
+            logical_key_str(&Key::Named(NamedKey::ArrowLeft)),
+            "ArrowLeft"
+        );
+        assert_eq!(logical_key_str(&Key::Named(NamedKey::F12)), "F12");
+        assert_eq!(
+            logical_key_str(&Key::Named(NamedKey::AudioVolumeUp)),
+            "AudioVolumeUp"
+        );
+        assert_eq!(logical_key_str(&Key::Named(NamedKey::Space)), " ");
+        assert_eq!(logical_key_str(&Key::Named(NamedKey::Super)), "Meta");
+        assert_eq!(logical_key_str(&Key::Named(NamedKey::Meta)), "Super");
+        assert_eq!(logical_key_str(&Key::Character("z".into())), "z");
+        assert_eq!(logical_key_str(&Key::Dead(Some('^'))), "^");
+        assert_eq!(logical_key_str(&Key::Dead(None)), "");
+    }
+
+    #[test]
+    fn test_utf8_range_to_utf16() {
+        use crate::utf8_range_to_utf16;
+
+        assert_eq!(utf8_range_to_utf16("abc", (1, 3)), Some((1, 3)));
+        // 'あ' is 3 bytes in UTF-8, 1 unit in UTF-16
+        assert_eq!(utf8_range_to_utf16("あいう", (3, 6)), Some((1, 2)));
+        // '😀' is 4 bytes in UTF-8, 2 units in UTF-16
+        assert_eq!(utf8_range_to_utf16("😀a", (4, 5)), Some((2, 3)));
+        assert_eq!(utf8_range_to_utf16("あ", (1, 3)), None);
+        assert_eq!(utf8_range_to_utf16("a", (0, 2)), None);
+    }
+
+    #[test]
+    fn test_choose_surface_format() {
+        use crate::screen::choose_surface_format;
+        use wgpu::TextureFormat as F;
+
+        let supported = [
+            F::Bgra8Unorm,
+            F::Bgra8UnormSrgb,
+            F::Rgba16Float,
+            F::Rgb10a2Unorm,
+        ];
+        // the first supported one in the preferred order
+        assert_eq!(
+            choose_surface_format(&supported, &[F::Rgba16Float]),
+            F::Rgba16Float
+        );
+        assert_eq!(
+            choose_surface_format(
+                &supported,
+                &[F::Rgba32Float, F::Rgb10a2Unorm, F::Rgba16Float]
+            ),
+            F::Rgb10a2Unorm
+        );
+        // fallback to the first sRGB format
+        assert_eq!(choose_surface_format(&supported, &[]), F::Bgra8UnormSrgb);
+        assert_eq!(
+            choose_surface_format(&supported, &[F::Rgba32Float]),
+            F::Bgra8UnormSrgb
+        );
+        // fallback to the first format if no sRGB format is supported
+        assert_eq!(
+            choose_surface_format(&[F::Rgba16Float, F::Bgra8Unorm], &[]),
+            F::Rgba16Float
+        );
+    }
+
+    #[test]
+    fn test_choose_alpha_mode() {
+        use crate::screen::choose_alpha_mode;
+        use wgpu::CompositeAlphaMode as M;
+
+        let supported = [M::Opaque, M::PreMultiplied];
+        // preferred mode is supported
+        assert_eq!(
+            choose_alpha_mode(&supported, M::PreMultiplied, false),
+            M::PreMultiplied
+        );
+        assert_eq!(choose_alpha_mode(&supported, M::Opaque, true), M::Opaque);
+        // fallback to a non-opaque mode for transparent windows
+        assert_eq!(
+            choose_alpha_mode(&supported, M::PostMultiplied, true),
+            M::PreMultiplied
+        );
+        assert_eq!(
+            choose_alpha_mode(&[M::Opaque, M::Inherit], M::Auto, true),
+            M::Inherit
+        );
+        // fallback to the first mode
+        assert_eq!(
+            choose_alpha_mode(&supported, M::PostMultiplied, false),
+            M::Opaque
+        );
+        assert_eq!(
+            choose_alpha_mode(&[M::Opaque], M::PreMultiplied, true),
+            M::Opaque
+        );
+        // `Auto` is not reported by the surface, so the engine chooses the mode.
+        assert_eq!(choose_alpha_mode(&supported, M::Auto, false), M::Opaque);
+        assert_eq!(
+            choose_alpha_mode(&supported, M::Auto, true),
+            M::PreMultiplied
+        );
+    }
 }
 
 #[repr(C)]
@@ -1569,7 +1987,7 @@ impl From<(u32, u32)> for SizeU32 {
 }
 
 #[repr(C)]
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy)]
 pub(crate) struct Tuple<T0, T1> {
     pub v0: T0,
     pub v1: T1,
@@ -1600,6 +2018,14 @@ pub(crate) struct Opt<T> {
     value: mem::MaybeUninit<T>,
 }
 
+impl<T: Copy> Clone for Opt<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Copy> Copy for Opt<T> {}
+
 impl<T> Default for Opt<T> {
     fn default() -> Self {
         Self::none()
@@ -1778,7 +2204,10 @@ impl ops::RangeBounds<u64> for RangeBoundsU64 {
 pub(crate) struct ImeInputData<'a> {
     tag: ImeInputDataTag,
     text: Slice<'a, u8>,
+    /// preedit cursor range in UTF-8 bytes
     range: Opt<(usize, usize)>,
+    /// preedit cursor range in UTF-16 code units
+    range_utf16: Opt<(usize, usize)>,
 }
 
 impl<'a> From<&'a Ime> for ImeInputData<'a> {
@@ -1794,26 +2223,141 @@ impl<'a> ImeInputData<'a> {
                 tag: ImeInputDataTag::Enabled,
                 text: Slice::default(),
                 range: None.into(),
+                range_utf16: None.into(),
             },
             Ime::Preedit(text, range) => Self {
                 tag: ImeInputDataTag::Preedit,
                 text: Slice::new(text.as_bytes()),
                 range: range.clone().into(),
+                range_utf16: range
+                    .and_then(|range| utf8_range_to_utf16(text, range))
+                    .into(),
             },
             Ime::Commit(text) => Self {
                 tag: ImeInputDataTag::Commit,
                 text: Slice::new(text.as_bytes()),
                 range: None.into(),
+                range_utf16: None.into(),
             },
             Ime::Disabled => Self {
                 tag: ImeInputDataTag::Disabled,
                 text: Slice::default(),
                 range: None.into(),
+                range_utf16: None.into(),
             },
         }
     }
 }
 
+/// Convert the range of UTF-8 bytes to the range of UTF-16 code units.
+/// Returns `None` if the range is not on char boundaries.
+fn utf8_range_to_utf16(text: &str, range: (usize, usize)) -> Option<(usize, usize)> {
+    let start = text.get(..range.0)?.encode_utf16().count();
+    let end = text.get(..range.1)?.encode_utf16().count();
+    Some((start, end))
+}
+
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[allow(dead_code)] // because values are from FFI
+pub(crate) enum ImePurpose {
+    Normal = 0,
+    Password = 1,
+    Terminal = 2,
+}
+
+impl ImePurpose {
+    pub fn to_winit_type(&self) -> winit::window::ImePurpose {
+        match self {
+            Self::Normal => winit::window::ImePurpose::Normal,
+            Self::Password => winit::window::ImePurpose::Password,
+            Self::Terminal => winit::window::ImePurpose::Terminal,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MouseScrollData {
+    pub delta_kind: MouseScrollDeltaKind,
+    /// lines (`delta_kind` is `Line`) or physical pixels (`delta_kind` is `Pixel`)
+    pub x_delta: f32,
+    pub y_delta: f32,
+    pub phase: TouchPhase,
+}
+
+impl MouseScrollData {
+    pub fn new(delta: &winit::event::MouseScrollDelta, phase: winit::event::TouchPhase) -> Self {
+        use winit::event::MouseScrollDelta;
+        let (delta_kind, x_delta, y_delta) = match delta {
+            MouseScrollDelta::LineDelta(x, y) => (MouseScrollDeltaKind::Line, *x, *y),
+            MouseScrollDelta::PixelDelta(pos) => {
+                (MouseScrollDeltaKind::Pixel, pos.x as f32, pos.y as f32)
+            }
+        };
+        Self {
+            delta_kind,
+            x_delta,
+            y_delta,
+            phase: phase.into(),
+        }
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MouseScrollDeltaKind {
+    /// mouse wheel
+    Line = 0,
+    /// touchpad
+    Pixel = 1,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TouchData {
+    /// unique identifier of a finger, which is the same from `Started` to `Ended` or `Cancelled`.
+    pub id: u64,
+    pub phase: TouchPhase,
+    /// physical position in the window
+    pub x: f32,
+    pub y: f32,
+    /// normalized force (0.0 ~ 1.0) if the device supports it
+    pub force: Opt<f32>,
+}
+
+impl From<&winit::event::Touch> for TouchData {
+    fn from(value: &winit::event::Touch) -> Self {
+        Self {
+            id: value.id,
+            phase: value.phase.into(),
+            x: value.location.x as f32,
+            y: value.location.y as f32,
+            force: value.force.map(|f| f.normalized() as f32).into(),
+        }
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TouchPhase {
+    Started = 0,
+    Moved = 1,
+    Ended = 2,
+    Cancelled = 3,
+}
+
+impl From<winit::event::TouchPhase> for TouchPhase {
+    fn from(value: winit::event::TouchPhase) -> Self {
+        match value {
+            winit::event::TouchPhase::Started => Self::Started,
+            winit::event::TouchPhase::Moved => Self::Moved,
+            winit::event::TouchPhase::Ended => Self::Ended,
+            winit::event::TouchPhase::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum ImeInputDataTag {
@@ -1823,10 +2367,44 @@ pub(crate) enum ImeInputDataTag {
     Disabled = 3,
 }
 
+#[repr(C)]
+pub(crate) struct AdapterInfo<'a> {
+    pub name: Slice<'a, u8>,
+    pub vendor: u32,
+    pub device: u32,
+    pub device_type: wgpu::DeviceType,
+    pub driver: Slice<'a, u8>,
+    pub driver_info: Slice<'a, u8>,
+    pub backend: wgpu::Backend,
+}
+
+impl<'a> From<&'a wgpu::AdapterInfo> for AdapterInfo<'a> {
+    fn from(value: &'a wgpu::AdapterInfo) -> Self {
+        Self {
+            name: Slice::new(value.name.as_bytes()),
+            vendor: value.vendor,
+            device: value.device,
+            device_type: value.device_type,
+            driver: Slice::new(value.driver.as_bytes()),
+            driver_info: Slice::new(value.driver_info.as_bytes()),
+            backend: value.backend,
+        }
+    }
+}
+
+/// Formats which are not supported by [`TextureFormat`] are excluded.
+#[repr(C)]
+pub(crate) struct SurfaceCapabilities<'a> {
+    pub formats: Slice<'a, TextureFormat>,
+    pub present_modes: Slice<'a, PresentMode>,
+    pub alpha_modes: Slice<'a, CompositeAlphaMode>,
+}
+
 #[repr(C)]
 pub(crate) struct ScreenInfo {
     pub backend: wgpu::Backend,
     pub surface_format: Opt<TextureFormat>,
+    pub alpha_mode: CompositeAlphaMode,
 }
 
 // To be used as a value type without release outside of Rust, the following conditions must be met.
@@ -1861,6 +2439,58 @@ impl MonitorId {
     }
 }
 
+#[repr(C)]
+#[derive(Debug, Default)]
+pub(crate) struct MonitorInfo {
+    /// byte length of the UTF-8 name (0 if the name is unknown)
+    pub name_len: usize,
+    /// physical position of the top-left corner in the desktop
+    pub x: i32,
+    pub y: i32,
+    /// physical size
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f64,
+    pub refresh_rate_millihertz: Opt<u32>,
+}
+
+impl From<&winit::monitor::MonitorHandle> for MonitorInfo {
+    fn from(value: &winit::monitor::MonitorHandle) -> Self {
+        let position = value.position();
+        let size = value.size();
+        Self {
+            name_len: value.name().map(|name| name.len()).unwrap_or(0),
+            x: position.x,
+            y: position.y,
+            width: size.width,
+            height: size.height,
+            scale_factor: value.scale_factor(),
+            refresh_rate_millihertz: value.refresh_rate_millihertz().into(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct VideoMode {
+    pub width: u32,
+    pub height: u32,
+    pub bit_depth: u16,
+    pub refresh_rate_millihertz: u32,
+}
+
+impl From<&winit::monitor::VideoModeHandle> for VideoMode {
+    fn from(value: &winit::monitor::VideoModeHandle) -> Self {
+        let size = value.size();
+        Self {
+            width: size.width,
+            height: size.height,
+            bit_depth: value.bit_depth(),
+            refresh_rate_millihertz: value.refresh_rate_millihertz(),
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) struct MouseButton {
@@ -1901,6 +2531,456 @@ impl From<winit::event::MouseButton> for MouseButton {
     }
 }
 
+#[repr(C)]
+pub(crate) struct KeyInputData<'a> {
+    /// physical key (none if the key is not supported)
+    pub key: Opt<KeyCode>,
+    pub pressed: bool,
+    pub repeat: bool,
+    pub location: KeyLocation,
+    pub modifiers: Modifiers,
+    pub logical_key_kind: LogicalKeyKind,
+    /// layout-dependent key as UTF-8
+    /// - `Named`: the name of the key value defined by W3C (e.g. "Enter", "ArrowLeft")
+    /// - `Character`: the characters without modifiers applied
+    /// - `Dead`: the character of the dead key if known
+    /// - `Unidentified`: empty
+    pub logical_key: Slice<'a, u8>,
+    /// text produced by the key with modifiers applied (empty if none)
+    pub text: Slice<'a, u8>,
+}
+
+impl<'a> KeyInputData<'a> {
+    pub fn new(
+        event: &'a winit::event::KeyEvent,
+        modifiers: winit::keyboard::ModifiersState,
+        logical_key: &'a str,
+    ) -> Self {
+        use winit::keyboard::PhysicalKey;
+        let key = match &event.physical_key {
+            PhysicalKey::Code(keycode) => keycode.try_into().ok(),
+            PhysicalKey::Unidentified(_) => None,
+        };
+        Self {
+            key: key.into(),
+            pressed: event.state.is_pressed(),
+            repeat: event.repeat,
+            location: event.location.into(),
+            modifiers: modifiers.into(),
+            logical_key_kind: (&event.logical_key).into(),
+            logical_key: Slice::new(logical_key.as_bytes()),
+            text: event
+                .text
+                .as_ref()
+                .map(|text| Slice::new(text.as_bytes()))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Get the logical key as a string. See [`KeyInputData::logical_key`].
+pub(crate) fn logical_key_str(key: &winit::keyboard::Key) -> std::borrow::Cow<'_, str> {
+    use winit::keyboard::Key;
+    match key {
+        Key::Named(named) => named_key_str(named).into(),
+        Key::Character(text) => text.as_str().into(),
+        Key::Dead(Some(c)) => c.to_string().into(),
+        Key::Dead(None) | Key::Unidentified(_) => "".into(),
+    }
+}
+
+/// Get the W3C key value of the named key.
+fn named_key_str(key: &winit::keyboard::NamedKey) -> &'static str {
+    use winit::keyboard::NamedKey;
+    match key {
+        // The names of `NamedKey` are the same as W3C except `Space`, `Super` and `Meta`.
+        NamedKey::Alt => "Alt",
+        NamedKey::AltGraph => "AltGraph",
+        NamedKey::CapsLock => "CapsLock",
+        NamedKey::Control => "Control",
+        NamedKey::Fn => "Fn",
+        NamedKey::FnLock => "FnLock",
+        NamedKey::NumLock => "NumLock",
+        NamedKey::ScrollLock => "ScrollLock",
+        NamedKey::Shift => "Shift",
+        NamedKey::Symbol => "Symbol",
+        NamedKey::SymbolLock => "SymbolLock",
+        NamedKey::Meta => "Super",
+        NamedKey::Hyper => "Hyper",
+        NamedKey::Super => "Meta",
+        NamedKey::Enter => "Enter",
+        NamedKey::Tab => "Tab",
+        NamedKey::Space => " ",
+        NamedKey::ArrowDown => "ArrowDown",
+        NamedKey::ArrowLeft => "ArrowLeft",
+        NamedKey::ArrowRight => "ArrowRight",
+        NamedKey::ArrowUp => "ArrowUp",
+        NamedKey::End => "End",
+        NamedKey::Home => "Home",
+        NamedKey::PageDown => "PageDown",
+        NamedKey::PageUp => "PageUp",
+        NamedKey::Backspace => "Backspace",
+        NamedKey::Clear => "Clear",
+        NamedKey::Copy => "Copy",
+        NamedKey::CrSel => "CrSel",
+        NamedKey::Cut => "Cut",
+        NamedKey::Delete => "Delete",
+        NamedKey::EraseEof => "EraseEof",
+        NamedKey::ExSel => "ExSel",
+        NamedKey::Insert => "Insert",
+        NamedKey::Paste => "Paste",
+        NamedKey::Redo => "Redo",
+        NamedKey::Undo => "Undo",
+        NamedKey::Accept => "Accept",
+        NamedKey::Again => "Again",
+        NamedKey::Attn => "Attn",
+        NamedKey::Cancel => "Cancel",
+        NamedKey::ContextMenu => "ContextMenu",
+        NamedKey::Escape => "Escape",
+        NamedKey::Execute => "Execute",
+        NamedKey::Find => "Find",
+        NamedKey::Help => "Help",
+        NamedKey::Pause => "Pause",
+        NamedKey::Play => "Play",
+        NamedKey::Props => "Props",
+        NamedKey::Select => "Select",
+        NamedKey::ZoomIn => "ZoomIn",
+        NamedKey::ZoomOut => "ZoomOut",
+        NamedKey::BrightnessDown => "BrightnessDown",
+        NamedKey::BrightnessUp => "BrightnessUp",
+        NamedKey::Eject => "Eject",
+        NamedKey::LogOff => "LogOff",
+        NamedKey::Power => "Power",
+        NamedKey::PowerOff => "PowerOff",
+        NamedKey::PrintScreen => "PrintScreen",
+        NamedKey::Hibernate => "Hibernate",
+        NamedKey::Standby => "Standby",
+        NamedKey::WakeUp => "WakeUp",
+        NamedKey::AllCandidates => "AllCandidates",
+        NamedKey::Alphanumeric => "Alphanumeric",
+        NamedKey::CodeInput => "CodeInput",
+        NamedKey::Compose => "Compose",
+        NamedKey::Convert => "Convert",
+        NamedKey::FinalMode => "FinalMode",
+        NamedKey::GroupFirst => "GroupFirst",
+        NamedKey::GroupLast => "GroupLast",
+        NamedKey::GroupNext => "GroupNext",
+        NamedKey::GroupPrevious => "GroupPrevious",
+        NamedKey::ModeChange => "ModeChange",
+        NamedKey::NextCandidate => "NextCandidate",
+        NamedKey::NonConvert => "NonConvert",
+        NamedKey::PreviousCandidate => "PreviousCandidate",
+        NamedKey::Process => "Process",
+        NamedKey::SingleCandidate => "SingleCandidate",
+        NamedKey::HangulMode => "HangulMode",
+        NamedKey::HanjaMode => "HanjaMode",
+        NamedKey::JunjaMode => "JunjaMode",
+        NamedKey::Eisu => "Eisu",
+        NamedKey::Hankaku => "Hankaku",
+        NamedKey::Hiragana => "Hiragana",
+        NamedKey::HiraganaKatakana => "HiraganaKatakana",
+        NamedKey::KanaMode => "KanaMode",
+        NamedKey::KanjiMode => "KanjiMode",
+        NamedKey::Katakana => "Katakana",
+        NamedKey::Romaji => "Romaji",
+        NamedKey::Zenkaku => "Zenkaku",
+        NamedKey::ZenkakuHankaku => "ZenkakuHankaku",
+        NamedKey::Soft1 => "Soft1",
+        NamedKey::Soft2 => "Soft2",
+        NamedKey::Soft3 => "Soft3",
+        NamedKey::Soft4 => "Soft4",
+        NamedKey::ChannelDown => "ChannelDown",
+        NamedKey::ChannelUp => "ChannelUp",
+        NamedKey::Close => "Close",
+        NamedKey::MailForward => "MailForward",
+        NamedKey::MailReply => "MailReply",
+        NamedKey::MailSend => "MailSend",
+        NamedKey::MediaClose => "MediaClose",
+        NamedKey::MediaFastForward => "MediaFastForward",
+        NamedKey::MediaPause => "MediaPause",
+        NamedKey::MediaPlay => "MediaPlay",
+        NamedKey::MediaPlayPause => "MediaPlayPause",
+        NamedKey::MediaRecord => "MediaRecord",
+        NamedKey::MediaRewind => "MediaRewind",
+        NamedKey::MediaStop => "MediaStop",
+        NamedKey::MediaTrackNext => "MediaTrackNext",
+        NamedKey::MediaTrackPrevious => "MediaTrackPrevious",
+        NamedKey::New => "New",
+        NamedKey::Open => "Open",
+        NamedKey::Print => "Print",
+        NamedKey::Save => "Save",
+        NamedKey::SpellCheck => "SpellCheck",
+        NamedKey::Key11 => "Key11",
+        NamedKey::Key12 => "Key12",
+        NamedKey::AudioBalanceLeft => "AudioBalanceLeft",
+        NamedKey::AudioBalanceRight => "AudioBalanceRight",
+        NamedKey::AudioBassBoostDown => "AudioBassBoostDown",
+        NamedKey::AudioBassBoostToggle => "AudioBassBoostToggle",
+        NamedKey::AudioBassBoostUp => "AudioBassBoostUp",
+        NamedKey::AudioFaderFront => "AudioFaderFront",
+        NamedKey::AudioFaderRear => "AudioFaderRear",
+        NamedKey::AudioSurroundModeNext => "AudioSurroundModeNext",
+        NamedKey::AudioTrebleDown => "AudioTrebleDown",
+        NamedKey::AudioTrebleUp => "AudioTrebleUp",
+        NamedKey::AudioVolumeDown => "AudioVolumeDown",
+        NamedKey::AudioVolumeUp => "AudioVolumeUp",
+        NamedKey::AudioVolumeMute => "AudioVolumeMute",
+        NamedKey::MicrophoneToggle => "MicrophoneToggle",
+        NamedKey::MicrophoneVolumeDown => "MicrophoneVolumeDown",
+        NamedKey::MicrophoneVolumeUp => "MicrophoneVolumeUp",
+        NamedKey::MicrophoneVolumeMute => "MicrophoneVolumeMute",
+        NamedKey::SpeechCorrectionList => "SpeechCorrectionList",
+        NamedKey::SpeechInputToggle => "SpeechInputToggle",
+        NamedKey::LaunchApplication1 => "LaunchApplication1",
+        NamedKey::LaunchApplication2 => "LaunchApplication2",
+        NamedKey::LaunchCalendar => "LaunchCalendar",
+        NamedKey::LaunchContacts => "LaunchContacts",
+        NamedKey::LaunchMail => "LaunchMail",
+        NamedKey::LaunchMediaPlayer => "LaunchMediaPlayer",
+        NamedKey::LaunchMusicPlayer => "LaunchMusicPlayer",
+        NamedKey::LaunchPhone => "LaunchPhone",
+        NamedKey::LaunchScreenSaver => "LaunchScreenSaver",
+        NamedKey::LaunchSpreadsheet => "LaunchSpreadsheet",
+        NamedKey::LaunchWebBrowser => "LaunchWebBrowser",
+        NamedKey::LaunchWebCam => "LaunchWebCam",
+        NamedKey::LaunchWordProcessor => "LaunchWordProcessor",
+        NamedKey::BrowserBack => "BrowserBack",
+        NamedKey::BrowserFavorites => "BrowserFavorites",
+        NamedKey::BrowserForward => "BrowserForward",
+        NamedKey::BrowserHome => "BrowserHome",
+        NamedKey::BrowserRefresh => "BrowserRefresh",
+        NamedKey::BrowserSearch => "BrowserSearch",
+        NamedKey::BrowserStop => "BrowserStop",
+        NamedKey::AppSwitch => "AppSwitch",
+        NamedKey::Call => "Call",
+        NamedKey::Camera => "Camera",
+        NamedKey::CameraFocus => "CameraFocus",
+        NamedKey::EndCall => "EndCall",
+        NamedKey::GoBack => "GoBack",
+        NamedKey::GoHome => "GoHome",
+        NamedKey::HeadsetHook => "HeadsetHook",
+        NamedKey::LastNumberRedial => "LastNumberRedial",
+        NamedKey::Notification => "Notification",
+        NamedKey::MannerMode => "MannerMode",
+        NamedKey::VoiceDial => "VoiceDial",
+        NamedKey::TV => "TV",
+        NamedKey::TV3DMode => "TV3DMode",
+        NamedKey::TVAntennaCable => "TVAntennaCable",
+        NamedKey::TVAudioDescription => "TVAudioDescription",
+        NamedKey::TVAudioDescriptionMixDown => "TVAudioDescriptionMixDown",
+        NamedKey::TVAudioDescriptionMixUp => "TVAudioDescriptionMixUp",
+        NamedKey::TVContentsMenu => "TVContentsMenu",
+        NamedKey::TVDataService => "TVDataService",
+        NamedKey::TVInput => "TVInput",
+        NamedKey::TVInputComponent1 => "TVInputComponent1",
+        NamedKey::TVInputComponent2 => "TVInputComponent2",
+        NamedKey::TVInputComposite1 => "TVInputComposite1",
+        NamedKey::TVInputComposite2 => "TVInputComposite2",
+        NamedKey::TVInputHDMI1 => "TVInputHDMI1",
+        NamedKey::TVInputHDMI2 => "TVInputHDMI2",
+        NamedKey::TVInputHDMI3 => "TVInputHDMI3",
+        NamedKey::TVInputHDMI4 => "TVInputHDMI4",
+        NamedKey::TVInputVGA1 => "TVInputVGA1",
+        NamedKey::TVMediaContext => "TVMediaContext",
+        NamedKey::TVNetwork => "TVNetwork",
+        NamedKey::TVNumberEntry => "TVNumberEntry",
+        NamedKey::TVPower => "TVPower",
+        NamedKey::TVRadioService => "TVRadioService",
+        NamedKey::TVSatellite => "TVSatellite",
+        NamedKey::TVSatelliteBS => "TVSatelliteBS",
+        NamedKey::TVSatelliteCS => "TVSatelliteCS",
+        NamedKey::TVSatelliteToggle => "TVSatelliteToggle",
+        NamedKey::TVTerrestrialAnalog => "TVTerrestrialAnalog",
+        NamedKey::TVTerrestrialDigital => "TVTerrestrialDigital",
+        NamedKey::TVTimer => "TVTimer",
+        NamedKey::AVRInput => "AVRInput",
+        NamedKey::AVRPower => "AVRPower",
+        NamedKey::ColorF0Red => "ColorF0Red",
+        NamedKey::ColorF1Green => "ColorF1Green",
+        NamedKey::ColorF2Yellow => "ColorF2Yellow",
+        NamedKey::ColorF3Blue => "ColorF3Blue",
+        NamedKey::ColorF4Grey => "ColorF4Grey",
+        NamedKey::ColorF5Brown => "ColorF5Brown",
+        NamedKey::ClosedCaptionToggle => "ClosedCaptionToggle",
+        NamedKey::Dimmer => "Dimmer",
+        NamedKey::DisplaySwap => "DisplaySwap",
+        NamedKey::DVR => "DVR",
+        NamedKey::Exit => "Exit",
+        NamedKey::FavoriteClear0 => "FavoriteClear0",
+        NamedKey::FavoriteClear1 => "FavoriteClear1",
+        NamedKey::FavoriteClear2 => "FavoriteClear2",
+        NamedKey::FavoriteClear3 => "FavoriteClear3",
+        NamedKey::FavoriteRecall0 => "FavoriteRecall0",
+        NamedKey::FavoriteRecall1 => "FavoriteRecall1",
+        NamedKey::FavoriteRecall2 => "FavoriteRecall2",
+        NamedKey::FavoriteRecall3 => "FavoriteRecall3",
+        NamedKey::FavoriteStore0 => "FavoriteStore0",
+        NamedKey::FavoriteStore1 => "FavoriteStore1",
+        NamedKey::FavoriteStore2 => "FavoriteStore2",
+        NamedKey::FavoriteStore3 => "FavoriteStore3",
+        NamedKey::Guide => "Guide",
+        NamedKey::GuideNextDay => "GuideNextDay",
+        NamedKey::GuidePreviousDay => "GuidePreviousDay",
+        NamedKey::Info => "Info",
+        NamedKey::InstantReplay => "InstantReplay",
+        NamedKey::Link => "Link",
+        NamedKey::ListProgram => "ListProgram",
+        NamedKey::LiveContent => "LiveContent",
+        NamedKey::Lock => "Lock",
+        NamedKey::MediaApps => "MediaApps",
+        NamedKey::MediaAudioTrack => "MediaAudioTrack",
+        NamedKey::MediaLast => "MediaLast",
+        NamedKey::MediaSkipBackward => "MediaSkipBackward",
+        NamedKey::MediaSkipForward => "MediaSkipForward",
+        NamedKey::MediaStepBackward => "MediaStepBackward",
+        NamedKey::MediaStepForward => "MediaStepForward",
+        NamedKey::MediaTopMenu => "MediaTopMenu",
+        NamedKey::NavigateIn => "NavigateIn",
+        NamedKey::NavigateNext => "NavigateNext",
+        NamedKey::NavigateOut => "NavigateOut",
+        NamedKey::NavigatePrevious => "NavigatePrevious",
+        NamedKey::NextFavoriteChannel => "NextFavoriteChannel",
+        NamedKey::NextUserProfile => "NextUserProfile",
+        NamedKey::OnDemand => "OnDemand",
+        NamedKey::Pairing => "Pairing",
+        NamedKey::PinPDown => "PinPDown",
+        NamedKey::PinPMove => "PinPMove",
+        NamedKey::PinPToggle => "PinPToggle",
+        NamedKey::PinPUp => "PinPUp",
+        NamedKey::PlaySpeedDown => "PlaySpeedDown",
+        NamedKey::PlaySpeedReset => "PlaySpeedReset",
+        NamedKey::PlaySpeedUp => "PlaySpeedUp",
+        NamedKey::RandomToggle => "RandomToggle",
+        NamedKey::RcLowBattery => "RcLowBattery",
+        NamedKey::RecordSpeedNext => "RecordSpeedNext",
+        NamedKey::RfBypass => "RfBypass",
+        NamedKey::ScanChannelsToggle => "ScanChannelsToggle",
+        NamedKey::ScreenModeNext => "ScreenModeNext",
+        NamedKey::Settings => "Settings",
+        NamedKey::SplitScreenToggle => "SplitScreenToggle",
+        NamedKey::STBInput => "STBInput",
+        NamedKey::STBPower => "STBPower",
+        NamedKey::Subtitle => "Subtitle",
+        NamedKey::Teletext => "Teletext",
+        NamedKey::VideoModeNext => "VideoModeNext",
+        NamedKey::Wink => "Wink",
+        NamedKey::ZoomToggle => "ZoomToggle",
+        NamedKey::F1 => "F1",
+        NamedKey::F2 => "F2",
+        NamedKey::F3 => "F3",
+        NamedKey::F4 => "F4",
+        NamedKey::F5 => "F5",
+        NamedKey::F6 => "F6",
+        NamedKey::F7 => "F7",
+        NamedKey::F8 => "F8",
+        NamedKey::F9 => "F9",
+        NamedKey::F10 => "F10",
+        NamedKey::F11 => "F11",
+        NamedKey::F12 => "F12",
+        NamedKey::F13 => "F13",
+        NamedKey::F14 => "F14",
+        NamedKey::F15 => "F15",
+        NamedKey::F16 => "F16",
+        NamedKey::F17 => "F17",
+        NamedKey::F18 => "F18",
+        NamedKey::F19 => "F19",
+        NamedKey::F20 => "F20",
+        NamedKey::F21 => "F21",
+        NamedKey::F22 => "F22",
+        NamedKey::F23 => "F23",
+        NamedKey::F24 => "F24",
+        NamedKey::F25 => "F25",
+        NamedKey::F26 => "F26",
+        NamedKey::F27 => "F27",
+        NamedKey::F28 => "F28",
+        NamedKey::F29 => "F29",
+        NamedKey::F30 => "F30",
+        NamedKey::F31 => "F31",
+        NamedKey::F32 => "F32",
+        NamedKey::F33 => "F33",
+        NamedKey::F34 => "F34",
+        NamedKey::F35 => "F35",
+        _ => "",
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LogicalKeyKind {
+    Named = 0,
+    Character = 1,
+    Dead = 2,
+    Unidentified = 3,
+}
+
+impl From<&winit::keyboard::Key> for LogicalKeyKind {
+    fn from(value: &winit::keyboard::Key) -> Self {
+        use winit::keyboard::Key;
+        match value {
+            Key::Named(_) => Self::Named,
+            Key::Character(_) => Self::Character,
+            Key::Dead(_) => Self::Dead,
+            Key::Unidentified(_) => Self::Unidentified,
+        }
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyLocation {
+    Standard = 0,
+    Left = 1,
+    Right = 2,
+    Numpad = 3,
+}
+
+impl From<winit::keyboard::KeyLocation> for KeyLocation {
+    fn from(value: winit::keyboard::KeyLocation) -> Self {
+        use winit::keyboard::KeyLocation as WKL;
+        match value {
+            WKL::Standard => Self::Standard,
+            WKL::Left => Self::Left,
+            WKL::Right => Self::Right,
+            WKL::Numpad => Self::Numpad,
+        }
+    }
+}
+
+/// modifier keys state as bit flags
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct Modifiers(u32);
+
+impl Modifiers {
+    pub const SHIFT: Self = Self(1 << 0);
+    pub const CONTROL: Self = Self(1 << 1);
+    pub const ALT: Self = Self(1 << 2);
+    pub const SUPER: Self = Self(1 << 3);
+}
+
+impl ops::BitOr for Modifiers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl From<winit::keyboard::ModifiersState> for Modifiers {
+    fn from(value: winit::keyboard::ModifiersState) -> Self {
+        let flags = [
+            (value.shift_key(), Self::SHIFT),
+            (value.control_key(), Self::CONTROL),
+            (value.alt_key(), Self::ALT),
+            (value.super_key(), Self::SUPER),
+        ];
+        flags
+            .into_iter()
+            .filter(|(pressed, _)| *pressed)
+            .fold(Self::default(), |acc, (_, flag)| acc | flag)
+    }
+}
+
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum KeyCode {
@@ -2329,9 +3409,14 @@ pub(crate) type EngineUnhandledErrorFn = extern "cdecl" fn(message: *const u8, l
 pub(crate) type ClearedEventFn = extern "cdecl" fn(screen_id: ScreenId);
 pub(crate) type RedrawRequestedEventFn = extern "cdecl" fn(screen_id: ScreenId) -> bool;
 pub(crate) type ResizedEventFn = extern "cdecl" fn(screen_id: ScreenId, width: u32, height: u32);
+/// `inner_size` is the suggested new inner size of the window.
+/// Overwrite it to request another size, or set 0 to leave it to the OS.
+pub(crate) type ScaleFactorChangedEventFn =
+    extern "cdecl" fn(screen_id: ScreenId, scale_factor: f64, inner_size: &mut SizeU32);
 pub(crate) type KeyboardEventFn =
     extern "cdecl" fn(screen_id: ScreenId, key: KeyCode, pressed: bool);
 
+pub(crate) type KeyInputEventFn = extern "cdecl" fn(screen_id: ScreenId, input: &KeyInputData);
 pub(crate) type CharReceivedEventFn = extern "cdecl" fn(screen_id: ScreenId, input: u32);
 pub(crate) type MouseButtonEventFn =
     extern "cdecl" fn(screen_id: ScreenId, button: MouseButton, pressed: bool);
@@ -2340,6 +3425,28 @@ pub(crate) type MouseWheelEventFn =
     extern "cdecl" fn(screen_id: ScreenId, x_delta: f32, y_delta: f32);
 pub(crate) type CursorMovedEventFn = extern "cdecl" fn(screen_id: ScreenId, x: f32, y: f32);
 pub(crate) type CursorEnteredLeftEventFn = extern "cdecl" fn(screen_id: ScreenId, entered: bool);
+pub(crate) type FocusedEventFn = extern "cdecl" fn(screen_id: ScreenId, focused: bool);
+pub(crate) type OccludedEventFn = extern "cdecl" fn(screen_id: ScreenId, occluded: bool);
 pub(crate) type ClosingEventFn = extern "cdecl" fn(screen_id: ScreenId, cancel: &mut bool);
 pub(crate) type ClosedEventFn = extern "cdecl" fn(screen_id: ScreenId) -> Option<Box<Screen>>;
+pub(crate) type MouseScrollEventFn =
+    extern "cdecl" fn(screen_id: ScreenId, scroll: &MouseScrollData);
+/// raw (unaccelerated and unbounded) motion of the mouse, which is sent to focused screens
+pub(crate) type RawMouseMotionEventFn =
+    extern "cdecl" fn(screen_id: ScreenId, x_delta: f64, y_delta: f64);
+/// `path` is UTF-8. (x, y) is the last known cursor position in the screen.
+pub(crate) type FileHoveredEventFn =
+    extern "cdecl" fn(screen_id: ScreenId, path: Slice<u8>, x: f32, y: f32);
+/// `path` is UTF-8. (x, y) is the last known cursor position in the screen.
+pub(crate) type FileDroppedEventFn =
+    extern "cdecl" fn(screen_id: ScreenId, path: Slice<u8>, x: f32, y: f32);
+pub(crate) type FileHoverCancelledEventFn = extern "cdecl" fn(screen_id: ScreenId);
+/// `theme` is never `System`.
+pub(crate) type ThemeChangedEventFn = extern "cdecl" fn(screen_id: ScreenId, theme: Theme);
+/// invoked from any thread. `message` is UTF-8. (recreate the device by `hikari_screen_recreate_device`)
+pub(crate) type DeviceLostEventFn =
+    extern "cdecl" fn(screen_id: ScreenId, reason: wgpu::DeviceLostReason, message: Slice<u8>);
+pub(crate) type TouchEventFn = extern "cdecl" fn(screen_id: ScreenId, touch: &TouchData);
+pub(crate) type MainThreadCallbackFn = extern "cdecl" fn(token: usize);
+pub(crate) type MainThreadCompletedFn = extern "cdecl" fn(token: usize, result: ApiResult);
 pub(crate) type DebugPrintlnFn = extern "cdecl" fn(message: *const u8, len: usize);
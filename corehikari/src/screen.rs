@@ -1,163 +1,870 @@
+use crate::engine::EngineErr;
 use crate::*;
 use dpi::{PhysicalSize, Size};
 use once_cell::sync::Lazy;
 use pollster::FutureExt;
 use regex::Regex;
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
+use std::collections::{hash_map, HashMap};
 use std::error::Error;
-use std::num;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::{mem, num, thread};
 use winit;
 use winit::event_loop::ActiveEventLoop;
 use winit::{dpi, window};
 
+static NEXT_SCREEN_SERIAL: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// clipboards of the screens (keyed by [`Screen::serial`]), which are used only on the main thread
+    static CLIPBOARDS: RefCell<HashMap<u64, ScreenClipboard>> = RefCell::new(HashMap::new());
+}
+
 pub(crate) struct Screen {
-    pub window: Arc<window::Window>,
-    pub surface: wgpu::Surface<'static>,
-    surface_config_data: SurfaceConfigData,
+    /// identifies the screen owned by the other side of FFI (See [`Screen::serial`])
+    serial: u64,
+    /// the thread which created the screen (the clipboard is used only on it)
+    main_thread: thread::ThreadId,
+    window: Option<Arc<window::Window>>,
+    visibility: Arc<WindowVisibility>,
+    throttle_when_hidden: bool,
+    always_on_top: AtomicBool,
+    /// replaced when the device is recreated
+    gpu: RwLock<ScreenGpu>,
     surface_size: Mutex<Cell<(num::NonZeroU32, num::NonZeroU32)>>,
-    pub device: wgpu::Device,
-    backend: wgpu::Backend,
-    pub queue: wgpu::Queue,
+    /// config to recreate the device
+    config: ScreenConfig<'static>,
+    on_unhandled_error: Arc<dyn Fn(&str) + Send + Sync>,
+    device_lost: Arc<DeviceLostNotifier>,
 }
 
 impl Screen {
     pub fn new(
-        config: &ScreenConfig,
+        config: &OwnedScreenConfig,
         event_loop: &ActiveEventLoop,
         on_unhandled_error: impl Fn(&str) + Send + Sync + 'static,
+        on_device_lost: impl Fn(ScreenId, wgpu::DeviceLostReason, &str) + Send + Sync + 'static,
     ) -> Result<Screen, Box<dyn Error>> {
-        let window = create_window(&config, event_loop)?;
-        if let Some(monitor) = window.current_monitor() {
-            let monitor_pos = monitor.position();
-            let monitor_size = monitor.size().cast::<i32>();
-            let window_size = window.outer_size().cast::<i32>();
-            let pos = dpi::PhysicalPosition::new(
-                monitor_pos.x + (monitor_size.width - window_size.width) / 2,
-                monitor_pos.y + (monitor_size.height - window_size.height) / 2,
-            );
+        let window = create_window(config, event_loop)?;
+        let monitor = match config.monitor.to_ref_option() {
+            Some(id) => Some(id.monitor()),
+            None => window.current_monitor(),
+        };
+        let pos = match (monitor, config.position.to_option()) {
+            (Some(monitor), Some(Tuple { v0: x, v1: y })) => {
+                let monitor_pos = monitor.position();
+                Some(dpi::PhysicalPosition::new(
+                    monitor_pos.x + x,
+                    monitor_pos.y + y,
+                ))
+            }
+            (Some(monitor), None) => {
+                let monitor_pos = monitor.position();
+                let monitor_size = monitor.size().cast::<i32>();
+                let window_size = window.outer_size().cast::<i32>();
+                Some(dpi::PhysicalPosition::new(
+                    monitor_pos.x + (monitor_size.width - window_size.width) / 2,
+                    monitor_pos.y + (monitor_size.height - window_size.height) / 2,
+                ))
+            }
+            // The position is in the desktop if the monitor is unknown.
+            (None, Some(Tuple { v0: x, v1: y })) => Some(dpi::PhysicalPosition::new(x, y)),
+            (None, None) => None,
+        };
+        if let Some(pos) = pos {
             window.set_outer_position(dpi::Position::Physical(pos));
         }
         window.set_ime_allowed(true);
-        window.focus_window();
-        Self::initialize(
-            window,
-            &config.backend,
-            &config.present_mode.to_wgpu_type(),
+        if !config.hidden {
+            window.focus_window();
+        }
+        let size = window.inner_size();
+        let mut screen = Self::initialize(
+            Some(Arc::new(window)),
+            size,
+            config,
+            &config.surface_formats,
             on_unhandled_error,
-        )
+            on_device_lost,
+        )?;
+        screen.throttle_when_hidden = config.throttle_when_hidden;
+        screen.visibility.set_hidden(config.hidden);
+        Ok(screen)
     }
 
-    fn initialize(
-        window: window::Window,
-        backends: &wgpu::Backends,
-        present_mode: &wgpu::PresentMode,
+    pub fn new_offscreen(
+        config: &ScreenConfig,
         on_unhandled_error: impl Fn(&str) + Send + Sync + 'static,
+        on_device_lost: impl Fn(ScreenId, wgpu::DeviceLostReason, &str) + Send + Sync + 'static,
     ) -> Result<Screen, Box<dyn Error>> {
-        let size = window.inner_size();
-        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor {
-            backends: *backends,
-            flags: wgpu::InstanceFlags::empty(), // TODO: set flags for debugging
-            backend_options: wgpu::BackendOptions::default(),
-        });
-        let window = Arc::new(window);
-        let surface = instance.create_surface(window.clone())?;
-        let adapter = instance
-            .request_adapter(&wgpu::RequestAdapterOptions {
-                power_preference: wgpu::PowerPreference::None,
-                compatible_surface: Some(&surface),
-                force_fallback_adapter: false,
-            })
-            .block_on()?;
-        let (device, queue) = adapter
-            .request_device(&wgpu::DeviceDescriptor {
-                required_features: wgpu::Features::ADDRESS_MODE_CLAMP_TO_BORDER,
-                required_limits: wgpu::Limits::default(),
-                memory_hints: wgpu::MemoryHints::default(),
-                trace: wgpu::Trace::Off,
-                label: None,
-            })
-            .block_on()?;
-        device.on_uncaptured_error(Box::new(move |error| {
-            static ANCI_ESC_SEQ_DECORATION: Lazy<Regex> =
-                Lazy::new(|| Regex::new("\x1b\\[[0-9;]*m").unwrap());
-            let message: String = error.to_string();
-            // Some error messages contain decorations of ANSI escape sequences.
-            // They should be removed.
-            let message = ANCI_ESC_SEQ_DECORATION.replace_all(&message, "");
-            on_unhandled_error(&message);
-        }));
-        let surface_config = {
-            let surface_caps = surface.get_capabilities(&adapter);
-            if surface_caps.present_modes.contains(present_mode) == false {
-                return Err(format!(
-                    "PresentMode '{:?}' is not supported in the current instance",
-                    *present_mode
-                )
-                .into());
-            }
+        let size = PhysicalSize::new(config.width, config.height);
+        Self::initialize(None, size, config, &[], on_unhandled_error, on_device_lost)
+    }
 
-            let surface_format = surface_caps
-                .formats
-                .iter()
-                .copied()
-                .filter(|f| f.is_srgb())
-                .next()
-                .unwrap_or(surface_caps.formats[0]);
-
-            new_default_surface_config(
-                surface_format,
-                size.width,
-                size.height,
-                *present_mode,
-                surface_caps.alpha_modes[0],
-            )
-        };
-        surface.configure(&device, &surface_config);
-        let size = (surface_config.width, surface_config.height);
+    fn initialize(
+        window: Option<Arc<window::Window>>,
+        size: PhysicalSize<u32>,
+        config: &ScreenConfig,
+        preferred_formats: &[wgpu::TextureFormat],
+        on_unhandled_error: impl Fn(&str) + Send + Sync + 'static,
+        on_device_lost: impl Fn(ScreenId, wgpu::DeviceLostReason, &str) + Send + Sync + 'static,
+    ) -> Result<Screen, Box<dyn Error>> {
+        let width = num::NonZeroU32::new(size.width).ok_or("cannot set 0 to surface width")?;
+        let height = num::NonZeroU32::new(size.height).ok_or("cannot set 0 to surface height")?;
+        let on_unhandled_error: Arc<dyn Fn(&str) + Send + Sync> = Arc::new(on_unhandled_error);
+        let device_lost = Arc::new(DeviceLostNotifier::new(on_device_lost));
+        let gpu = ScreenGpu::new(
+            window.as_ref(),
+            config,
+            config.present_mode.to_wgpu_type(),
+            preferred_formats,
+            (width, height),
+            &on_unhandled_error,
+            &device_lost,
+        )?;
         Ok(Screen {
+            serial: NEXT_SCREEN_SERIAL.fetch_add(1, Ordering::Relaxed),
+            main_thread: thread::current().id(),
             window,
-            surface,
-            surface_config_data: surface_config.into(),
-            surface_size: Mutex::new(Cell::new((
-                num::NonZeroU32::new(size.0).expect("cannot set 0 to surface width"),
-                num::NonZeroU32::new(size.1).expect("cannot set 0 to surface height"),
-            ))),
-            device,
-            backend: adapter.get_info().backend,
-            queue,
+            visibility: Default::default(),
+            throttle_when_hidden: false,
+            always_on_top: AtomicBool::new(false),
+            gpu: RwLock::new(gpu),
+            surface_size: Mutex::new(Cell::new((width, height))),
+            config: copy_config_without_borrowed(config),
+            on_unhandled_error,
+            device_lost,
         })
     }
 
+    /// Unique number of the screen, which is never reused unlike the address of the screen.
+    pub fn serial(&self) -> u64 {
+        self.serial
+    }
+
+    /// Recreate the device, the queue and the surface. (e.g. after the device is lost)
+    /// All GPU objects created from the old device must be recreated.
+    pub fn recreate_device(&self) -> Result<(), Box<dyn Error>> {
+        let size = self.surface_size.lock().unwrap().get();
+        let mut gpu = self.gpu.write().unwrap();
+        // Keep the present mode changed by `set_present_mode`, and the format if possible.
+        let surface_config_data = gpu.surface_config_data;
+        // Drop the old surface before creating the new one, because only one surface (swapchain)
+        // can be created for a window. (DX12, Vulkan)
+        // If recreating fails, the screen keeps the window and can be recreated again.
+        let old_surface = match &gpu.surface {
+            ScreenSurface::Offscreen(_) => ScreenSurface::Offscreen(Mutex::new(None)),
+            ScreenSurface::Window(_) | ScreenSurface::Lost => ScreenSurface::Lost,
+        };
+        drop(mem::replace(&mut gpu.surface, old_surface));
+        *gpu = ScreenGpu::new(
+            self.window.as_ref(),
+            &self.config,
+            surface_config_data.present_mode,
+            &[surface_config_data.format],
+            size,
+            &self.on_unhandled_error,
+            &self.device_lost,
+        )?;
+        Ok(())
+    }
+
+    pub fn surface_capabilities(&self) -> wgpu::SurfaceCapabilities {
+        let gpu = self.gpu.read().unwrap();
+        match &gpu.surface {
+            ScreenSurface::Window(surface) => surface.get_capabilities(&gpu.adapter),
+            ScreenSurface::Offscreen(_) => wgpu::SurfaceCapabilities {
+                formats: vec![OFFSCREEN_FORMAT],
+                present_modes: vec![wgpu::PresentMode::Fifo],
+                alpha_modes: vec![wgpu::CompositeAlphaMode::Opaque],
+                usages: gpu.surface_config_data.usage,
+            },
+            ScreenSurface::Lost => Default::default(),
+        }
+    }
+
+    pub fn set_present_mode(&self, present_mode: wgpu::PresentMode) -> Result<(), Box<dyn Error>> {
+        let mut gpu = self.gpu.write().unwrap();
+        let surface = match &gpu.surface {
+            ScreenSurface::Window(surface) => surface,
+            // Offscreen screens are not presented, so they have no present mode to switch.
+            ScreenSurface::Offscreen(_) => return Err(EngineErr::NO_WINDOW.into()),
+            ScreenSurface::Lost => return Err(SURFACE_LOST.into()),
+        };
+        let surface_caps = surface.get_capabilities(&gpu.adapter);
+        if !surface_caps.present_modes.contains(&present_mode) {
+            return Err(format!(
+                "PresentMode '{:?}' is not supported in the current instance",
+                present_mode
+            )
+            .into());
+        }
+        gpu.surface_config_data.present_mode = present_mode;
+        let (width, height) = self.surface_size.lock().unwrap().get();
+        self.resize_surface_of(&gpu, width.get(), height.get());
+        Ok(())
+    }
+
+    pub fn device(&self) -> wgpu::Device {
+        self.gpu.read().unwrap().device.clone()
+    }
+
+    pub fn queue(&self) -> wgpu::Queue {
+        self.gpu.read().unwrap().queue.clone()
+    }
+
+    pub fn device_lost_notifier(&self) -> Arc<DeviceLostNotifier> {
+        self.device_lost.clone()
+    }
+
     pub fn get_info(&self) -> ScreenInfo {
-        let format = self.surface_config_data.format;
+        let gpu = self.gpu.read().unwrap();
+        let format = gpu.surface_config_data.format;
         ScreenInfo {
-            backend: self.backend,
+            backend: gpu.backend,
             surface_format: format.try_into().ok().into(),
+            alpha_mode: gpu.surface_config_data.alpha_mode.into(),
+        }
+    }
+
+    /// Get the window of the screen. Offscreen screens have no window.
+    pub fn window(&self) -> Result<&window::Window, EngineErr> {
+        self.window.as_deref().ok_or(EngineErr::NO_WINDOW)
+    }
+
+    pub fn window_arc(&self) -> Result<Arc<window::Window>, EngineErr> {
+        self.window.clone().ok_or(EngineErr::NO_WINDOW)
+    }
+
+    pub fn visibility(&self) -> Arc<WindowVisibility> {
+        self.visibility.clone()
+    }
+
+    pub fn set_visible(&self, visible: bool) -> Result<(), EngineErr> {
+        let window = self.window()?;
+        window.set_visible(visible);
+        self.visibility.set_hidden(!visible);
+        if visible {
+            window.focus_window();
+        }
+        Ok(())
+    }
+
+    pub fn request_redraw(&self) {
+        if self.throttle_when_hidden && self.visibility.is_hidden() {
+            return;
+        }
+        // Offscreen screens are redrawn by the frame timer of the engine.
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+
+    pub fn clipboard_text(&self) -> Result<String, Box<dyn Error>> {
+        self.with_clipboard(|clipboard| clipboard.read())
+    }
+
+    pub fn set_clipboard_text(&self, text: &str) -> Result<(), Box<dyn Error>> {
+        self.with_clipboard(|clipboard| clipboard.write(text.to_owned()))
+    }
+
+    fn with_clipboard<T>(
+        &self,
+        f: impl FnOnce(&mut window_clipboard::Clipboard) -> Result<T, Box<dyn Error>>,
+    ) -> Result<T, Box<dyn Error>> {
+        let window = self.window_arc()?;
+        // The clipboard is not `Send` (`NSPasteboard` is bound to the main thread on macOS),
+        // so it is kept in the thread local storage of the main thread.
+        if thread::current().id() != self.main_thread {
+            return Err("the clipboard can only be used on the main thread".into());
+        }
+        CLIPBOARDS.with(|clipboards| {
+            let mut clipboards = clipboards.borrow_mut();
+            let clipboard = match clipboards.entry(self.serial) {
+                hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                hash_map::Entry::Vacant(entry) => {
+                    // The window outlives the clipboard because the clipboard holds the window.
+                    let clipboard = unsafe { window_clipboard::Clipboard::connect(&*window)? };
+                    entry.insert(ScreenClipboard {
+                        clipboard,
+                        _window: window,
+                    })
+                }
+            };
+            f(&mut clipboard.clipboard)
+        })
+    }
+
+    pub fn set_icon(&self, icon: &IconImage) -> Result<(), Box<dyn Error>> {
+        let icon = icon.to_winit_type()?;
+        // Offscreen screens have no icon. Just ignore it.
+        if let Some(window) = &self.window {
+            window.set_window_icon(Some(icon));
+        }
+        Ok(())
+    }
+
+    pub fn inner_size(&self) -> PhysicalSize<u32> {
+        match &self.window {
+            Some(window) => window.inner_size(),
+            None => {
+                let (width, height) = self.surface_size.lock().unwrap().get();
+                PhysicalSize::new(width.get(), height.get())
+            }
         }
     }
 
     pub fn set_inner_size(&self, width: num::NonZeroU32, height: num::NonZeroU32) {
-        let size = dpi::PhysicalSize::<u32>::new(width.into(), height.into());
-        _ = self.window.request_inner_size(size);
+        match &self.window {
+            Some(window) => {
+                let size = dpi::PhysicalSize::<u32>::new(width.into(), height.into());
+                _ = window.request_inner_size(size);
+            }
+            None => self.resize_surface(width.get(), height.get()),
+        }
+    }
+
+    pub fn set_style(&self, style: WindowStyle) -> Result<(), EngineErr> {
+        apply_window_style(self.window()?, style);
+        Ok(())
+    }
+
+    /// Set the minimum inner size. (`None` means no limit)
+    pub fn set_min_inner_size(&self, size: Option<SizeU32>) -> Result<(), EngineErr> {
+        // The surface size must not be zero.
+        let size = size.map_or(PhysicalSize::new(1, 1), |size| {
+            PhysicalSize::new(size.width.max(1), size.height.max(1))
+        });
+        self.window()?
+            .set_min_inner_size(Some(Size::Physical(size)));
+        Ok(())
+    }
+
+    /// Set the maximum inner size. (`None` means no limit)
+    pub fn set_max_inner_size(&self, size: Option<SizeU32>) -> Result<(), EngineErr> {
+        let size = size.map(|size| Size::Physical(PhysicalSize::new(size.width, size.height)));
+        self.window()?.set_max_inner_size(size);
+        Ok(())
+    }
+
+    pub fn set_always_on_top(&self, always_on_top: bool) -> Result<(), EngineErr> {
+        let level = match always_on_top {
+            true => window::WindowLevel::AlwaysOnTop,
+            false => window::WindowLevel::Normal,
+        };
+        self.window()?.set_window_level(level);
+        // winit cannot get the window level, so it is kept here.
+        self.always_on_top.store(always_on_top, Ordering::Relaxed);
+        Ok(())
+    }
+
+    pub fn is_always_on_top(&self) -> Result<bool, EngineErr> {
+        self.window()?;
+        Ok(self.always_on_top.load(Ordering::Relaxed))
+    }
+
+    /// Make the window fullscreen exclusively with the video mode of the monitor.
+    pub fn set_exclusive_fullscreen(
+        &self,
+        monitor: &winit::monitor::MonitorHandle,
+        video_mode: &VideoMode,
+    ) -> Result<(), Box<dyn Error>> {
+        let window = self.window()?;
+        let video_mode = monitor
+            .video_modes()
+            .find(|x| VideoMode::from(x) == *video_mode)
+            .ok_or("the video mode is not supported by the monitor")?;
+        window.set_fullscreen(Some(window::Fullscreen::Exclusive(video_mode)));
+        Ok(())
     }
 
     pub fn resize_surface(&self, width: u32, height: u32) {
+        let gpu = self.gpu.read().unwrap();
+        self.resize_surface_of(&gpu, width, height);
+    }
+
+    fn resize_surface_of(&self, gpu: &ScreenGpu, width: u32, height: u32) {
         if let (Some(width), Some(height)) =
             (num::NonZeroU32::new(width), num::NonZeroU32::new(height))
         {
             let lock = self.surface_size.lock().unwrap();
             lock.set((width, height));
-            let config = self.surface_config_data.to_wgpu_type(width, height);
-            self.surface.configure(&self.device, &config);
+            match &gpu.surface {
+                ScreenSurface::Window(surface) => {
+                    let config = gpu.surface_config_data.to_wgpu_type(width, height);
+                    surface.configure(&gpu.device, &config);
+                }
+                ScreenSurface::Offscreen(current) => {
+                    // The texture is recreated with the new size on the next frame.
+                    current.lock().unwrap().take();
+                }
+                // The surface is configured with the size when it is recreated.
+                ScreenSurface::Lost => {}
+            }
         }
     }
+
+    /// Get the texture to render the next frame to.
+    /// Returns `None` if the surface is lost and has been reconfigured. (Skip the frame in that case.)
+    pub fn get_current_texture(&self) -> Result<Option<SurfaceTexture>, wgpu::SurfaceError> {
+        let gpu = self.gpu.read().unwrap();
+        match &gpu.surface {
+            ScreenSurface::Window(surface) => match surface.get_current_texture() {
+                Ok(surface_texture) => Ok(Some(SurfaceTexture::Surface(surface_texture))),
+                Err(wgpu::SurfaceError::Lost) => {
+                    let size = self.inner_size();
+                    self.resize_surface_of(&gpu, size.width, size.height);
+                    Ok(None)
+                }
+                Err(err) => Err(err),
+            },
+            ScreenSurface::Offscreen(current) => {
+                let mut current = current.lock().unwrap();
+                let texture = current.get_or_insert_with(|| {
+                    let (width, height) = self.surface_size.lock().unwrap().get();
+                    gpu.device.create_texture(&wgpu::TextureDescriptor {
+                        label: None,
+                        size: wgpu::Extent3d {
+                            width: width.get(),
+                            height: height.get(),
+                            depth_or_array_layers: 1,
+                        },
+                        mip_level_count: 1,
+                        sample_count: 1,
+                        dimension: wgpu::TextureDimension::D2,
+                        format: gpu.surface_config_data.format,
+                        usage: gpu.surface_config_data.usage | wgpu::TextureUsages::COPY_SRC,
+                        view_formats: &[],
+                    })
+                });
+                Ok(Some(SurfaceTexture::Offscreen(texture.clone())))
+            }
+            ScreenSurface::Lost => Err(wgpu::SurfaceError::Lost),
+        }
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        // Screens are dropped on the main thread by the engine, where the clipboard is kept.
+        let clipboard = CLIPBOARDS
+            .try_with(|clipboards| clipboards.borrow_mut().remove(&self.serial))
+            .ok()
+            .flatten();
+        drop(clipboard);
+    }
+}
+
+/// GPU objects of the screen
+struct ScreenGpu {
+    surface: ScreenSurface,
+    surface_config_data: SurfaceConfigData,
+    adapter: wgpu::Adapter,
+    device: wgpu::Device,
+    backend: wgpu::Backend,
+    queue: wgpu::Queue,
+}
+
+impl ScreenGpu {
+    fn new(
+        window: Option<&Arc<window::Window>>,
+        config: &ScreenConfig,
+        present_mode: wgpu::PresentMode,
+        preferred_formats: &[wgpu::TextureFormat],
+        size: (num::NonZeroU32, num::NonZeroU32),
+        on_unhandled_error: &Arc<dyn Fn(&str) + Send + Sync>,
+        device_lost: &Arc<DeviceLostNotifier>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let (width, height) = size;
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor {
+            backends: config.backend,
+            flags: wgpu::InstanceFlags::empty(), // TODO: set flags for debugging
+            backend_options: wgpu::BackendOptions::default(),
+        });
+        let (adapter, surface) = match window {
+            Some(window) => {
+                let surface = instance.create_surface(window.clone())?;
+                let adapter = select_adapter(&instance, Some(&surface), config)?;
+                (adapter, Some(surface))
+            }
+            None => {
+                // Prefer a software adapter (lavapipe, llvmpipe, WARP) so that it works on machines without GPU,
+                // unless the adapter is specified.
+                let prefer_fallback = config.adapter_index.to_ref_option().is_none()
+                    && config.power_preference == wgpu::PowerPreference::None;
+                let fallback = match prefer_fallback {
+                    true => {
+                        request_adapter(&instance, None, wgpu::PowerPreference::None, true).ok()
+                    }
+                    false => None,
+                };
+                let adapter = match fallback {
+                    Some(adapter) => adapter,
+                    None => select_adapter(&instance, None, config)?,
+                };
+                (adapter, None)
+            }
+        };
+        let on_unhandled_error = on_unhandled_error.clone();
+        let (device, queue) =
+            request_device(&adapter, config, move |message| on_unhandled_error(message))?;
+        device_lost.register(&device);
+        let (surface, surface_config) = match surface {
+            Some(surface) => {
+                let present_mode = &present_mode;
+                let surface_caps = surface.get_capabilities(&adapter);
+                if surface_caps.present_modes.contains(present_mode) == false {
+                    return Err(format!(
+                        "PresentMode '{:?}' is not supported in the current instance",
+                        *present_mode
+                    )
+                    .into());
+                }
+
+                let surface_config = new_default_surface_config(
+                    choose_surface_format(&surface_caps.formats, preferred_formats),
+                    width.get(),
+                    height.get(),
+                    *present_mode,
+                    choose_alpha_mode(
+                        &surface_caps.alpha_modes,
+                        config.alpha_mode.to_wgpu_type(),
+                        config.transparent,
+                    ),
+                );
+                surface.configure(&device, &surface_config);
+                (ScreenSurface::Window(surface), surface_config)
+            }
+            None => {
+                let surface_config = new_default_surface_config(
+                    OFFSCREEN_FORMAT,
+                    width.get(),
+                    height.get(),
+                    wgpu::PresentMode::Fifo,
+                    wgpu::CompositeAlphaMode::Opaque,
+                );
+                (ScreenSurface::Offscreen(Mutex::new(None)), surface_config)
+            }
+        };
+        Ok(Self {
+            surface,
+            surface_config_data: surface_config.into(),
+            device,
+            backend: adapter.get_info().backend,
+            adapter,
+            queue,
+        })
+    }
+}
+
+/// Notifies that the device of the screen is lost.
+/// The screen id is set after the screen is passed to the engine.
+pub(crate) struct DeviceLostNotifier {
+    screen_id: OnceLock<ScreenId>,
+    callback: Box<DeviceLostFn>,
+}
+
+type DeviceLostFn = dyn Fn(ScreenId, wgpu::DeviceLostReason, &str) + Send + Sync;
+
+impl DeviceLostNotifier {
+    fn new(
+        callback: impl Fn(ScreenId, wgpu::DeviceLostReason, &str) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            screen_id: OnceLock::new(),
+            callback: Box::new(callback),
+        }
+    }
+
+    pub fn set_screen_id(&self, screen_id: ScreenId) {
+        _ = self.screen_id.set(screen_id);
+    }
+
+    fn register(self: &Arc<Self>, device: &wgpu::Device) {
+        let notifier = self.clone();
+        device.set_device_lost_callback(move |reason, message| {
+            // Nothing to notify if the screen is not passed to the engine yet.
+            if let Some(screen_id) = notifier.screen_id.get() {
+                (notifier.callback)(*screen_id, reason, &message);
+            }
+        });
+    }
+}
+
+/// Visibility of the window, which is updated by the engine on window events and by `Screen::set_visible`.
+#[derive(Debug, Default)]
+pub(crate) struct WindowVisibility {
+    occluded: AtomicBool,
+    minimized: AtomicBool,
+    hidden: AtomicBool,
+}
+
+impl WindowVisibility {
+    pub fn is_hidden(&self) -> bool {
+        self.occluded.load(Ordering::Relaxed)
+            || self.minimized.load(Ordering::Relaxed)
+            || self.hidden.load(Ordering::Relaxed)
+    }
+
+    pub fn set_hidden(&self, hidden: bool) {
+        self.hidden.store(hidden, Ordering::Relaxed);
+    }
+
+    pub fn set_occluded(&self, occluded: bool) {
+        self.occluded.store(occluded, Ordering::Relaxed);
+    }
+
+    pub fn set_minimized(&self, minimized: bool) {
+        self.minimized.store(minimized, Ordering::Relaxed);
+    }
+}
+
+enum ScreenSurface {
+    Window(wgpu::Surface<'static>),
+    /// render-target texture used instead of the surface of a window
+    Offscreen(Mutex<Option<wgpu::Texture>>),
+    /// The surface of the window was dropped and recreating the device failed.
+    /// (Recreate the device again by `Screen::recreate_device`)
+    Lost,
+}
+
+const SURFACE_LOST: &str = "the surface of the window is lost. (recreate the device)";
+
+/// The texture of the current frame, which is presented to the window or kept as the offscreen render target.
+pub(crate) enum SurfaceTexture {
+    Surface(wgpu::SurfaceTexture),
+    Offscreen(wgpu::Texture),
+}
+
+impl SurfaceTexture {
+    pub fn texture(&self) -> &wgpu::Texture {
+        match self {
+            Self::Surface(surface_texture) => &surface_texture.texture,
+            Self::Offscreen(texture) => texture,
+        }
+    }
+
+    pub fn present(self) {
+        match self {
+            Self::Surface(surface_texture) => surface_texture.present(),
+            // The rendered texture remains as the render target, and it can be read by copying it to a buffer.
+            Self::Offscreen(_) => {}
+        }
+    }
+}
+
+const OFFSCREEN_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+/// Select the adapter specified by the index, or request the adapter matching the config.
+fn select_adapter(
+    instance: &wgpu::Instance,
+    compatible_surface: Option<&wgpu::Surface>,
+    config: &ScreenConfig,
+) -> Result<wgpu::Adapter, Box<dyn Error>> {
+    let Some(index) = config.adapter_index.to_option() else {
+        let adapter = request_adapter(
+            instance,
+            compatible_surface,
+            config.power_preference,
+            config.force_fallback_adapter,
+        )?;
+        return Ok(adapter);
+    };
+    let adapter = instance
+        .enumerate_adapters(config.backend)
+        .into_iter()
+        .nth(index)
+        .ok_or("adapter index is out of range")?;
+    if let Some(surface) = compatible_surface {
+        if !adapter.is_surface_supported(surface) {
+            return Err("the adapter is not compatible with the surface".into());
+        }
+    }
+    Ok(adapter)
+}
+
+fn request_adapter(
+    instance: &wgpu::Instance,
+    compatible_surface: Option<&wgpu::Surface>,
+    power_preference: wgpu::PowerPreference,
+    force_fallback_adapter: bool,
+) -> Result<wgpu::Adapter, wgpu::RequestAdapterError> {
+    instance
+        .request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference,
+            compatible_surface,
+            force_fallback_adapter,
+        })
+        .block_on()
+}
+
+fn request_device(
+    adapter: &wgpu::Adapter,
+    config: &ScreenConfig,
+    on_unhandled_error: impl Fn(&str) + Send + Sync + 'static,
+) -> Result<(wgpu::Device, wgpu::Queue), Box<dyn Error>> {
+    let supported_features = adapter.features();
+    let required_features =
+        config.required_features.to_wgpu_type() | wgpu::Features::ADDRESS_MODE_CLAMP_TO_BORDER;
+    let missing_features = required_features - supported_features;
+    if !missing_features.is_empty() {
+        return Err(format!("required features are not supported: {}", missing_features).into());
+    }
+    let optional_features = config.optional_features.to_wgpu_type() & supported_features;
+
+    let required_limits = match config.use_adapter_limits {
+        true => adapter.limits(),
+        false => config.limits.to_option().unwrap_or_default(),
+    };
+    let mut missing_limits = vec![];
+    required_limits.check_limits_with_fail_fn(&adapter.limits(), false, |name, _, _| {
+        missing_limits.push(name)
+    });
+    if !missing_limits.is_empty() {
+        return Err(format!(
+            "required limits are not supported: {}",
+            missing_limits.join(", ")
+        )
+        .into());
+    }
+
+    let (device, queue) = adapter
+        .request_device(&wgpu::DeviceDescriptor {
+            required_features: required_features | optional_features,
+            required_limits,
+            memory_hints: wgpu::MemoryHints::default(),
+            trace: wgpu::Trace::Off,
+            label: None,
+        })
+        .block_on()?;
+    device.on_uncaptured_error(Box::new(move |error| {
+        static ANCI_ESC_SEQ_DECORATION: Lazy<Regex> =
+            Lazy::new(|| Regex::new("\x1b\\[[0-9;]*m").unwrap());
+        let message: String = error.to_string();
+        // Some error messages contain decorations of ANSI escape sequences.
+        // They should be removed.
+        let message = ANCI_ESC_SEQ_DECORATION.replace_all(&message, "");
+        on_unhandled_error(&message);
+    }));
+    Ok((device, queue))
 }
 
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) struct ScreenId(usize);
 
+/// Cursor image created from RGBA pixels, which can be shared by screens.
+#[derive(Debug, Clone)]
+pub(crate) struct CustomCursor(Arc<CustomCursorInner>);
+
+#[derive(Debug)]
+struct CustomCursorInner {
+    source: Mutex<Option<window::CustomCursorSource>>,
+    cursor: OnceLock<window::CustomCursor>,
+}
+
+/// Copy the config without the borrowed data. (`icon`, `title` and `preferred_surface_formats`)
+fn copy_config_without_borrowed(config: &ScreenConfig) -> ScreenConfig<'static> {
+    ScreenConfig {
+        style: config.style,
+        width: config.width,
+        height: config.height,
+        backend: config.backend,
+        present_mode: config.present_mode,
+        offscreen: config.offscreen,
+        throttle_when_hidden: config.throttle_when_hidden,
+        icon: Opt::none(),
+        transparent: config.transparent,
+        alpha_mode: config.alpha_mode,
+        title: Slice::empty(),
+        position: config.position,
+        monitor: config.monitor.to_option().into(),
+        hidden: config.hidden,
+        power_preference: config.power_preference,
+        adapter_index: config.adapter_index,
+        force_fallback_adapter: config.force_fallback_adapter,
+        required_features: config.required_features,
+        optional_features: config.optional_features,
+        limits: config.limits.to_ref_option().cloned().into(),
+        use_adapter_limits: config.use_adapter_limits,
+        preferred_surface_formats: Slice::empty(),
+    }
+}
+
+/// Clipboard connected to the display of the window.
+struct ScreenClipboard {
+    clipboard: window_clipboard::Clipboard,
+    /// keeps the window alive while the clipboard is connected (dropped after the clipboard)
+    _window: Arc<window::Window>,
+}
+
+/// [`ScreenConfig`] with the borrowed data copied, which can be sent to the main thread.
+#[derive(Debug)]
+pub(crate) struct OwnedScreenConfig {
+    config: ScreenConfig<'static>,
+    pub window_icon: Option<window::Icon>,
+    pub owned_title: String,
+    pub surface_formats: Vec<wgpu::TextureFormat>,
+}
+
+impl OwnedScreenConfig {
+    pub fn new(config: &ScreenConfig) -> Result<Self, Box<dyn Error>> {
+        let window_icon = match config.icon.to_ref_option() {
+            Some(icon) => Some(icon.to_winit_type()?),
+            None => None,
+        };
+        let owned_title = config.title.as_str()?.to_owned();
+        let surface_formats = config
+            .preferred_surface_formats
+            .iter()
+            .map(|format| format.to_wgpu_type())
+            .collect();
+        Ok(Self {
+            config: copy_config_without_borrowed(config),
+            window_icon,
+            owned_title,
+            surface_formats,
+        })
+    }
+}
+
+impl std::ops::Deref for OwnedScreenConfig {
+    type Target = ScreenConfig<'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.config
+    }
+}
+
+impl CustomCursor {
+    pub fn from_rgba(
+        rgba: &[u8],
+        width: u16,
+        height: u16,
+        hotspot_x: u16,
+        hotspot_y: u16,
+    ) -> Result<Self, window::BadImage> {
+        let source = window::CustomCursor::from_rgba(rgba, width, height, hotspot_x, hotspot_y)?;
+        Ok(Self(Arc::new(CustomCursorInner {
+            source: Mutex::new(Some(source)),
+            cursor: OnceLock::new(),
+        })))
+    }
+
+    /// Get the cursor, which is created by the event loop at the first time.
+    pub fn get_or_create(&self, event_loop: &ActiveEventLoop) -> window::CustomCursor {
+        let inner = &self.0;
+        inner
+            .cursor
+            .get_or_init(|| {
+                let source = inner.source.lock().unwrap().take();
+                event_loop.create_custom_cursor(source.expect("cursor source is already used"))
+            })
+            .clone()
+    }
+}
+
 #[derive(Clone, Copy)]
 struct SurfaceConfigData {
     pub usage: wgpu::TextureUsages,
@@ -216,27 +923,73 @@ fn new_default_surface_config(
 }
 
 fn create_window(
-    config: &ScreenConfig,
+    config: &OwnedScreenConfig,
     event_loop: &ActiveEventLoop,
 ) -> Result<window::Window, winit::error::OsError> {
-    use winit::window::{Fullscreen, WindowButtons};
-
     let window = event_loop.create_window(
         winit::window::Window::default_attributes()
-            .with_title("")
+            .with_title(&config.owned_title)
+            .with_visible(!config.hidden)
             .with_inner_size(Size::Physical(PhysicalSize::new(
                 config.width,
                 config.height,
             )))
             .with_min_inner_size(Size::Physical(PhysicalSize::new(1, 1)))
-            .with_theme(None),
+            .with_theme(None)
+            .with_window_icon(config.window_icon.clone())
+            .with_transparent(config.transparent),
     )?;
-    match config.style {
+    apply_window_style(&window, config.style);
+    Ok(window)
+}
+
+/// Choose the first supported format in `preferred`.
+/// Otherwise, choose the first sRGB format, or the first supported format.
+pub(crate) fn choose_surface_format(
+    supported: &[wgpu::TextureFormat],
+    preferred: &[wgpu::TextureFormat],
+) -> wgpu::TextureFormat {
+    preferred
+        .iter()
+        .copied()
+        .find(|f| supported.contains(f))
+        .or_else(|| supported.iter().copied().find(|f| f.is_srgb()))
+        .unwrap_or(supported[0])
+}
+
+/// Choose the preferred alpha mode if supported.
+/// Otherwise, choose a non-opaque mode for transparent windows, or the first supported mode.
+pub(crate) fn choose_alpha_mode(
+    supported: &[wgpu::CompositeAlphaMode],
+    preferred: wgpu::CompositeAlphaMode,
+    transparent: bool,
+) -> wgpu::CompositeAlphaMode {
+    if supported.contains(&preferred) {
+        return preferred;
+    }
+    if transparent {
+        let non_opaque = supported
+            .iter()
+            .copied()
+            .find(|&mode| mode != wgpu::CompositeAlphaMode::Opaque);
+        if let Some(mode) = non_opaque {
+            return mode;
+        }
+    }
+    supported[0]
+}
+
+fn apply_window_style(window: &window::Window, style: WindowStyle) {
+    use winit::window::{Fullscreen, WindowButtons};
+
+    match style {
         WindowStyle::Default => {
+            window.set_fullscreen(None);
             window.set_resizable(true);
             window.set_enabled_buttons(WindowButtons::all())
         }
         WindowStyle::Fixed => {
+            window.set_fullscreen(None);
             window.set_resizable(false);
             window.set_enabled_buttons(WindowButtons::CLOSE | WindowButtons::MINIMIZE);
         }
@@ -244,5 +997,4 @@ fn create_window(
             window.set_fullscreen(Some(Fullscreen::Borderless(None)));
         }
     }
-    Ok(window)
 }